@@ -22,6 +22,7 @@ use binaryninja::{
 };
 
 use gimli::{
+    constants,
     AttributeValue::{Encoding, Flag, UnitRef},
     // BigEndian,
     DebuggingInformationEntry,
@@ -134,6 +135,26 @@ fn get_info_string<R: Reader>(
         InstructionTextTokenContents::TypeName, // TODO : KeywordToken?
     )]));
 
+    // The version decides how some attributes (like DW_AT_high_pc) are encoded, so show it on the unit's root
+    if matches!(
+        die_node.tag(),
+        constants::DW_TAG_compile_unit
+            | constants::DW_TAG_partial_unit
+            | constants::DW_TAG_type_unit
+            | constants::DW_TAG_skeleton_unit
+    ) {
+        disassembly_lines.push(DisassemblyTextLine::from(vec![InstructionTextToken::new(
+            BnString::new(format!("  ; DWARF version {}", unit.header.version())),
+            InstructionTextTokenContents::Text,
+        )]));
+    }
+
+    let low_pc = die_node
+        .attr_value(constants::DW_AT_low_pc)
+        .ok()
+        .flatten()
+        .and_then(|value| dwarf.attr_address(unit, value).ok().flatten());
+
     let mut attrs = die_node.attrs();
     while let Some(attr) = attrs.next().unwrap() {
         let mut attr_line: Vec<InstructionTextToken> = Vec::with_capacity(5);
@@ -170,7 +191,29 @@ fn get_info_string<R: Reader>(
             InstructionTextTokenContents::Text,
         ));
 
-        if let Ok(Some(addr)) = dwarf.attr_address(unit, attr.value()) {
+        // Since DWARF 4, DW_AT_high_pc may be a constant offset from DW_AT_low_pc rather than an address
+        let high_pc_offset = match (attr.name(), low_pc) {
+            (constants::DW_AT_high_pc, Some(low_pc)) => attr.udata_value().map(|o| (low_pc, o)),
+            _ => None,
+        };
+
+        if let Some((low_pc, offset)) = high_pc_offset {
+            // Both values come from the file, so a malformed offset can overflow
+            match low_pc.checked_add(offset) {
+                Some(addr) => attr_line.push(InstructionTextToken::new(
+                    BnString::new(format!("0x{:08x}", addr)),
+                    InstructionTextTokenContents::Integer(addr),
+                )),
+                None => attr_line.push(InstructionTextToken::new(
+                    BnString::new("<overflow>"),
+                    InstructionTextTokenContents::Text,
+                )),
+            }
+            attr_line.push(InstructionTextToken::new(
+                BnString::new(format!(" (low_pc + 0x{:x})", offset)),
+                InstructionTextTokenContents::Text,
+            ));
+        } else if let Ok(Some(addr)) = dwarf.attr_address(unit, attr.value()) {
             let addr_string = format!("0x{:08x}", addr);
             attr_line.push(InstructionTextToken::new(
                 BnString::new(addr_string),
@@ -284,12 +327,52 @@ fn process_tree<R: Reader>(
     );
 
     let mut children = die_node.children();
-    while let Some(child) = children.next().unwrap() {
-        process_tree(view, dwarf, unit, graph, &new_node, child);
+    loop {
+        match children.next() {
+            Ok(Some(child)) => process_tree(view, dwarf, unit, graph, &new_node, child),
+            Ok(None) => break,
+            Err(e) => {
+                add_error_node(
+                    graph,
+                    &new_node,
+                    format!("Failed to parse child DIE: {}", e),
+                );
+                break;
+            }
+        }
     }
     // }
 }
 
+fn add_error_node(graph: &FlowGraph, graph_parent: &FlowGraphNode, message: String) {
+    let error_node = FlowGraphNode::new(graph);
+    error_node.set_lines(vec![message.as_str()]);
+
+    graph.append(&error_node);
+    graph_parent.add_outgoing_edge(
+        BranchType::UnconditionalBranch,
+        &error_node,
+        &EdgeStyle::default(),
+    );
+}
+
+fn process_unit<R: Reader>(
+    view: &BinaryView,
+    dwarf: &Dwarf<R>,
+    unit: &Unit<R>,
+    graph: &FlowGraph,
+    graph_root: &FlowGraphNode,
+) -> Result<(), Error> {
+    let mut entries = unit.entries();
+    if let Some((_, entry)) = entries.next_dfs()? {
+        let mut tree = unit.entries_tree(Some(entry.offset()))?;
+        let root = tree.root()?;
+
+        process_tree(view, dwarf, unit, graph, graph_root, root);
+    }
+    Ok(())
+}
+
 fn dump_dwarf(bv: &BinaryView) {
     let view = if bv.section_by_name(".debug_info").is_ok() {
         bv.to_owned()
@@ -300,28 +383,14 @@ fn dump_dwarf(bv: &BinaryView) {
     // TODO : Accommodate Endianity
     let get_section_data_little =
         |section_id: SectionId| -> Result<CustomReader<LittleEndian>, Error> {
-            if let Ok(section) = view.section_by_name(section_id.name()) {
-                let offset = section.start();
-                let len = section.len();
-                if len == 0 {
-                    Ok(CustomReader::new(
-                        DataBufferWrapper::new(DataBuffer::default()),
-                        LittleEndian,
-                    ))
-                } else if let Ok(read_buffer) = view.read_buffer(offset, len) {
-                    Ok(CustomReader::new(
-                        DataBufferWrapper::new(read_buffer),
-                        LittleEndian,
-                    ))
-                } else {
-                    Err(Error::Io)
-                }
-            } else {
-                Ok(CustomReader::new(
-                    DataBufferWrapper::new(DataBuffer::default()),
-                    LittleEndian,
-                ))
-            }
+            let data = match view.section_by_name(section_id.name()) {
+                Ok(section) => view.section_contents(&section).map_err(|_| Error::Io)?,
+                Err(_) => DataBuffer::default(),
+            };
+            Ok(CustomReader::new(
+                DataBufferWrapper::new(data),
+                LittleEndian,
+            ))
         };
 
     let empty_reader_little = |_: SectionId| -> Result<CustomReader<LittleEndian>, Error> {
@@ -341,20 +410,39 @@ fn dump_dwarf(bv: &BinaryView) {
 
     let dwarf = Dwarf::load(&get_section_data_little, &empty_reader_little).unwrap();
 
+    // Units are processed one at a time as their headers are read; a malformed unit is reported
+    // in the graph and skipped rather than aborting the rest of the dump
     let mut iter = dwarf.units();
-    while let Some(header) = iter.next().unwrap() {
-        let unit = dwarf.unit(header).unwrap();
-        let mut entries = unit.entries();
-        let mut depth = 0;
-
-        if let Some((delta_depth, entry)) = entries.next_dfs().unwrap() {
-            depth += delta_depth;
-            assert!(depth >= 0);
-
-            let mut tree = unit.entries_tree(Some(entry.offset())).unwrap();
-            let root = tree.root().unwrap();
+    loop {
+        let header = match iter.next() {
+            Ok(Some(header)) => header,
+            Ok(None) => break,
+            Err(e) => {
+                // Without a valid header we can't know where the next unit starts
+                add_error_node(
+                    &graph,
+                    &graph_root,
+                    format!("Failed to read unit header: {}", e),
+                );
+                break;
+            }
+        };
 
-            process_tree(&view, &dwarf, &unit, &graph, &graph_root, root);
+        let unit_offset = match header.offset() {
+            UnitSectionOffset::DebugInfoOffset(o) => o.0,
+            UnitSectionOffset::DebugTypesOffset(o) => o.0,
+        }
+        .into_u64();
+
+        let result = dwarf
+            .unit(header)
+            .and_then(|unit| process_unit(&view, &dwarf, &unit, &graph, &graph_root));
+        if let Err(e) = result {
+            add_error_node(
+                &graph,
+                &graph_root,
+                format!("Failed to parse unit at #0x{:08x}: {}", unit_offset, e),
+            );
         }
     }
 
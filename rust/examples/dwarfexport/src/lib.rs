@@ -1,24 +1,29 @@
 use gimli::{
     constants,
     write::{
-        Address, AttributeValue, DwarfUnit, EndianVec, Expression, Range, RangeList, Sections,
-        UnitEntryId,
+        Address, AttributeValue, DwarfUnit, EndianVec, Expression, LineProgram, LineString, Range,
+        RangeList, Sections, UnitEntryId,
     },
+    LineEncoding,
 };
 use object::{write, Architecture, BinaryFormat, SectionKind};
-use std::fs;
+use std::{fs, path::Path};
 
 use binaryninja::{
+    architecture::Architecture as _,
     binaryview::{BinaryView, BinaryViewBase, BinaryViewExt},
     command::{register, Command},
     interaction,
-    interaction::{FormResponses, FormResponses::Index},
+    interaction::{
+        FormResponses, FormResponses::Index, MessageBoxButtonResult, MessageBoxButtonSet,
+        MessageBoxIcon,
+    },
     logger::init,
     rc::Ref,
     symbol::SymbolType,
     types::{Conf, MemberAccess, StructureType, Type, TypeClass},
 };
-use log::{error, LevelFilter};
+use log::{error, info, LevelFilter};
 
 fn export_type(
     t: &Type,
@@ -249,7 +254,66 @@ fn export_type(
                 .set(gimli::DW_AT_byte_size, AttributeValue::Data8(t.width()));
             array_die_uid
         }
-        TypeClass::FunctionTypeClass => dwarf.unit.add(root, constants::DW_TAG_unspecified_type),
+        TypeClass::FunctionTypeClass => {
+            let function_die_uid = dwarf.unit.add(root, constants::DW_TAG_subroutine_type);
+            defined_types.push((t.to_owned(), function_die_uid));
+
+            dwarf
+                .unit
+                .get_mut(function_die_uid)
+                .set(gimli::DW_AT_prototyped, AttributeValue::Flag(true));
+
+            if let Ok(Conf {
+                contents: return_type,
+                ..
+            }) = t.return_value()
+            {
+                if return_type.type_class() != TypeClass::VoidTypeClass {
+                    let target_die_uid = AttributeValue::UnitRef(export_type(
+                        &return_type,
+                        bv,
+                        defined_types,
+                        dwarf,
+                    ));
+                    dwarf
+                        .unit
+                        .get_mut(function_die_uid)
+                        .set(gimli::DW_AT_type, target_die_uid);
+                }
+            }
+
+            for parameter in t.parameters().unwrap_or_default() {
+                let param_die_uid = dwarf
+                    .unit
+                    .add(function_die_uid, constants::DW_TAG_formal_parameter);
+
+                if !parameter.name.is_empty() {
+                    dwarf.unit.get_mut(param_die_uid).set(
+                        gimli::DW_AT_name,
+                        AttributeValue::String(parameter.name.as_bytes().to_vec()),
+                    );
+                }
+
+                let target_die_uid = AttributeValue::UnitRef(export_type(
+                    &parameter.t.contents,
+                    bv,
+                    defined_types,
+                    dwarf,
+                ));
+                dwarf
+                    .unit
+                    .get_mut(param_die_uid)
+                    .set(gimli::DW_AT_type, target_die_uid);
+            }
+
+            if t.has_variable_arguments().contents {
+                dwarf
+                    .unit
+                    .add(function_die_uid, constants::DW_TAG_unspecified_parameters);
+            }
+
+            function_die_uid
+        }
         TypeClass::VarArgsTypeClass => dwarf.unit.add(root, constants::DW_TAG_unspecified_type),
         TypeClass::ValueTypeClass => dwarf.unit.add(root, constants::DW_TAG_unspecified_type),
         TypeClass::NamedTypeReferenceClass => {
@@ -490,6 +554,89 @@ fn export_data_vars(
     }
 }
 
+// There is no source to point the line table at, so we write out a disassembly listing next to the
+// DWARF and map every instruction to its line in that listing. The listing becomes the unit's primary
+// source file, so the unit is named after it for debuggers to resolve the same file.
+fn export_line_info(bv: &BinaryView, dwarf: &mut DwarfUnit, listing_path: &Path) -> String {
+    let directory = listing_path
+        .parent()
+        .map(|p| p.to_string_lossy().to_string())
+        .unwrap_or_default();
+    let file_name = listing_path
+        .file_name()
+        .map(|f| f.to_string_lossy().to_string())
+        .unwrap_or_default();
+
+    let mut line_program = LineProgram::new(
+        dwarf.unit.encoding(),
+        LineEncoding::default(),
+        LineString::String(directory.as_bytes().to_vec()),
+        LineString::String(file_name.as_bytes().to_vec()),
+        None,
+    );
+    let directory_id = line_program.default_directory();
+    let file_id = line_program.add_file(
+        LineString::String(file_name.as_bytes().to_vec()),
+        directory_id,
+        None,
+    );
+
+    let mut listing = String::new();
+    let mut line: u64 = 0;
+
+    for function in &bv.functions() {
+        listing.push_str(&format!("{}:\n", function.symbol().full_name()));
+        line += 1;
+
+        let basic_blocks = function.basic_blocks();
+        let mut blocks: Vec<_> = basic_blocks.iter().collect();
+        blocks.sort_by_key(|block| block.raw_start());
+
+        for block in blocks {
+            let arch = block.arch();
+
+            // Each basic block is contiguous, so it gets its own sequence
+            line_program.begin_sequence(Some(Address::Constant(block.raw_start() - bv.start()))); // TODO: Relocations
+            for address in block.iter() {
+                let data = bv.read_vec(address, arch.max_instr_len());
+                let text = match arch.instruction_text(&data, address) {
+                    Some((_, tokens)) => tokens.iter().map(|t| t.text().to_string()).collect(),
+                    None => String::from("??"),
+                };
+                // Same base as the addresses in the line table
+                listing.push_str(&format!("    {:#010x}  {}\n", address - bv.start(), text));
+                line += 1;
+
+                let row = line_program.row();
+                row.file = file_id;
+                row.line = line;
+                row.address_offset = address - block.raw_start();
+                line_program.generate_row();
+            }
+            line_program.end_sequence(block.raw_length());
+        }
+
+        listing.push('\n');
+        line += 1;
+    }
+
+    dwarf.unit.line_program = line_program;
+
+    let root = dwarf.unit.root();
+    let root = dwarf.unit.get_mut(root);
+    root.set(gimli::DW_AT_stmt_list, AttributeValue::LineProgramRef);
+    root.set(
+        gimli::DW_AT_name,
+        AttributeValue::String(file_name.as_bytes().to_vec()),
+    );
+    root.set(
+        gimli::DW_AT_comp_dir,
+        AttributeValue::String(directory.as_bytes().to_vec()),
+    );
+
+    listing
+}
+
 fn present_form() -> Vec<FormResponses> {
     // TODO : Verify inputs (like save location) so that we can fail early
     // TODO : Add Language field
@@ -530,6 +677,14 @@ fn present_form() -> Vec<FormResponses> {
             &["Coff", "Elf", "MachO", "Pe", "Wasm", "Xcoff"],
             None,
         )
+        .choice_field(
+            "Line Information",
+            &[
+                "None",
+                "Disassembly listing (written next to the save location, with a .s extension)",
+            ],
+            Some(1),
+        )
         .get_form_input("Export as DWARF")
 }
 
@@ -633,6 +788,20 @@ fn write_dwarf<T: gimli::Endianity>(
     }
 }
 
+fn confirm_overwrite(path: &Path) -> bool {
+    if !path.exists() {
+        return true;
+    }
+
+    let response = interaction::show_message_box(
+        "Export as DWARF",
+        &format!("`{}` already exists. Overwrite it?", path.display()),
+        MessageBoxButtonSet::YesNoButtonSet,
+        MessageBoxIcon::WarningIcon,
+    );
+    response == MessageBoxButtonResult::YesButton
+}
+
 fn export_dwarf(bv: &BinaryView) {
     let responses = present_form();
 
@@ -643,9 +812,18 @@ fn export_dwarf(bv: &BinaryView) {
     };
 
     // Create a container for a single compilation unit.
-    // TODO : Add attributes to the compilation unit DIE?
     let mut dwarf = DwarfUnit::new(encoding);
 
+    let root = dwarf.unit.root();
+    dwarf.unit.get_mut(root).set(
+        gimli::DW_AT_name,
+        AttributeValue::String(bv.file().filename().as_bytes().to_vec()),
+    );
+    dwarf.unit.get_mut(root).set(
+        gimli::DW_AT_producer,
+        AttributeValue::String("Binary Ninja".as_bytes().to_vec()),
+    );
+
     // Everything has types, so we need to track what is already defined globally as to not duplicate type entries
     let mut defined_types: Vec<(Ref<Type>, UnitEntryId)> = vec![];
     export_types(bv, &mut dwarf, &mut defined_types);
@@ -654,6 +832,23 @@ fn export_dwarf(bv: &BinaryView) {
     // TODO: Export all symbols instead of just data vars?
     // TODO: Sections? Segments?
 
+    if let (Some(FormResponses::String(filename)), Some(Index(1))) =
+        (responses.first(), responses.get(3))
+    {
+        let listing_path = Path::new(filename).with_extension("s");
+        if confirm_overwrite(&listing_path) {
+            let listing = export_line_info(bv, &mut dwarf, &listing_path);
+            if let Err(err) = fs::write(&listing_path, listing) {
+                error!("Failed to write disassembly listing: {}", err);
+            }
+        } else {
+            info!(
+                "Not overwriting `{}`; exporting without line information",
+                listing_path.display()
+            );
+        }
+    }
+
     if bv.default_endianness() == binaryninja::Endianness::LittleEndian {
         write_dwarf(responses, gimli::LittleEndian, &mut dwarf);
     } else {
@@ -0,0 +1,101 @@
+use std::fs;
+
+use binaryninja::{
+    binaryview::{BinaryView, BinaryViewExt},
+    command::{register, Command},
+    interaction::{self, MessageBoxButtonSet, MessageBoxIcon},
+    logger,
+    platform::TypeParser,
+};
+use log::{error, info, LevelFilter};
+
+fn import_header(view: &BinaryView) {
+    let path = match interaction::get_open_filename_input("Select Header File", "*.h *.hpp") {
+        Some(path) => path,
+        None => return,
+    };
+
+    let platform = match view.default_platform() {
+        Some(platform) => platform,
+        None => {
+            error!("Cannot import a header into a view without a platform");
+            return;
+        }
+    };
+
+    let source = match fs::read_to_string(&path) {
+        Ok(source) => source,
+        Err(err) => {
+            error!("Failed to read `{}`: {}", path.display(), err);
+            return;
+        }
+    };
+
+    // Headers commonly include their siblings by relative path
+    let include_dirs: Vec<_> = path.parent().into_iter().collect();
+    let filename = path.to_string_lossy().to_string();
+
+    let result = match platform.parse_types_from_source(
+        source.as_str(),
+        filename.as_str(),
+        &include_dirs,
+        "",
+    ) {
+        Ok(result) => result,
+        Err(errors) => {
+            interaction::show_message_box(
+                "Import Header File",
+                &format!("Failed to parse `{}`:\n{}", path.display(), errors),
+                MessageBoxButtonSet::OKButtonSet,
+                MessageBoxIcon::ErrorIcon,
+            );
+            return;
+        }
+    };
+
+    for (name, t) in &result.types {
+        view.define_user_type(name.as_str(), t);
+    }
+
+    // Prototypes are only applied to functions that the view already knows by name
+    let mut typed_functions = 0;
+    for (name, t) in &result.functions {
+        for symbol in &view.symbols_by_name(name.as_str()) {
+            for function in &view.functions_at(symbol.address()) {
+                function.set_user_type(t);
+                typed_functions += 1;
+            }
+        }
+    }
+
+    info!(
+        "Imported {} types and {} function prototypes from `{}`",
+        result.types.len(),
+        typed_functions,
+        path.display()
+    );
+}
+
+struct ImportHeader;
+
+impl Command for ImportHeader {
+    fn action(&self, view: &BinaryView) {
+        import_header(view);
+    }
+
+    fn valid(&self, view: &BinaryView) -> bool {
+        view.default_platform().is_some()
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn CorePluginInit() -> bool {
+    logger::init(LevelFilter::Info).expect("Unable to initialize logger");
+
+    register(
+        "Import Header File...",
+        "Parse a C header against the view's platform and define the resulting types",
+        ImportHeader {},
+    );
+    true
+}
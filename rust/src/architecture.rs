@@ -21,6 +21,7 @@ use binaryninjacore_sys::*;
 use std::borrow::{Borrow, Cow};
 use std::collections::HashMap;
 use std::ffi::{CStr, CString};
+use std::fmt;
 use std::hash::Hash;
 use std::mem::zeroed;
 use std::ops;
@@ -394,6 +395,18 @@ pub trait Architecture: 'static + Sized + AsRef<CoreArchitecture> {
 }
 
 pub struct CoreRegisterInfo(*mut BNArchitecture, u32, BNRegisterInfo);
+
+impl fmt::Debug for CoreRegisterInfo {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("CoreRegisterInfo")
+            .field("parent", &self.parent())
+            .field("offset", &self.offset())
+            .field("size", &self.size())
+            .field("implicit_extend", &self.implicit_extend())
+            .finish()
+    }
+}
+
 impl RegisterInfo for CoreRegisterInfo {
     type RegType = CoreRegister;
 
@@ -450,6 +463,12 @@ impl Register for CoreRegister {
     }
 }
 
+impl fmt::Debug for CoreRegister {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} ({})", self.name(), self.id())
+    }
+}
+
 #[derive(Copy, Clone, Eq, PartialEq, Hash)]
 pub struct CoreFlag(*mut BNArchitecture, u32);
 impl Flag for CoreFlag {
@@ -1217,17 +1236,23 @@ where
     where
         A: 'static + Architecture<Handle = CustomArchitectureHandle<A>> + Send + Sync,
     {
-        let custom_arch = unsafe { &*(ctxt as *mut A) };
-        let data = unsafe { slice::from_raw_parts(data, len) };
-        let result = unsafe { &mut *(result as *mut InstructionInfo) };
-
-        match custom_arch.instruction_info(data, addr) {
-            Some(info) => {
-                result.0 = info.0;
-                true
-            }
-            None => false,
-        }
+        ffi_wrap!(
+            "Architecture::instruction_info",
+            {
+                let custom_arch = unsafe { &*(ctxt as *mut A) };
+                let data = unsafe { slice::from_raw_parts(data, len) };
+                let result = unsafe { &mut *(result as *mut InstructionInfo) };
+
+                match custom_arch.instruction_info(data, addr) {
+                    Some(info) => {
+                        result.0 = info.0;
+                        true
+                    }
+                    None => false,
+                }
+            },
+            false
+        )
     }
 
     extern "C" fn cb_get_instruction_text<A>(
@@ -1241,26 +1266,32 @@ where
     where
         A: 'static + Architecture<Handle = CustomArchitectureHandle<A>> + Send + Sync,
     {
-        let custom_arch = unsafe { &*(ctxt as *mut A) };
-        let data = unsafe { slice::from_raw_parts(data, *len) };
-        let result = unsafe { &mut *result };
-
-        match custom_arch.instruction_text(data, addr) {
-            Some((res_size, mut res_tokens)) => {
-                unsafe {
-                    // TODO: Can't use into_raw_parts as it's unstable so we do this instead...
-                    let r_ptr = res_tokens.as_mut_ptr();
-                    let r_count = res_tokens.len();
-                    mem::forget(res_tokens);
-
-                    *result = &mut (*r_ptr).0;
-                    *count = r_count;
-                    *len = res_size;
+        ffi_wrap!(
+            "Architecture::instruction_text",
+            {
+                let custom_arch = unsafe { &*(ctxt as *mut A) };
+                let data = unsafe { slice::from_raw_parts(data, *len) };
+                let result = unsafe { &mut *result };
+
+                match custom_arch.instruction_text(data, addr) {
+                    Some((res_size, mut res_tokens)) => {
+                        unsafe {
+                            // TODO: Can't use into_raw_parts as it's unstable so we do this instead...
+                            let r_ptr = res_tokens.as_mut_ptr();
+                            let r_count = res_tokens.len();
+                            mem::forget(res_tokens);
+
+                            *result = &mut (*r_ptr).0;
+                            *count = r_count;
+                            *len = res_size;
+                        }
+                        true
+                    }
+                    None => false,
                 }
-                true
-            }
-            None => false,
-        }
+            },
+            false
+        )
     }
 
     extern "C" fn cb_free_instruction_text(tokens: *mut BNInstructionTextToken, count: usize) {
@@ -1278,21 +1309,28 @@ where
     where
         A: 'static + Architecture<Handle = CustomArchitectureHandle<A>> + Send + Sync,
     {
-        let custom_arch = unsafe { &*(ctxt as *mut A) };
-        let custom_arch_handle = CustomArchitectureHandle {
-            handle: ctxt as *mut A,
-        };
+        // On a panic the instruction fails, but whatever was already emitted through the lifter stays
+        ffi_wrap!(
+            "Architecture::instruction_llil",
+            {
+                let custom_arch = unsafe { &*(ctxt as *mut A) };
+                let custom_arch_handle = CustomArchitectureHandle {
+                    handle: ctxt as *mut A,
+                };
 
-        let data = unsafe { slice::from_raw_parts(data, *len) };
-        let mut lifter = unsafe { Lifter::from_raw(custom_arch_handle, il) };
+                let data = unsafe { slice::from_raw_parts(data, *len) };
+                let mut lifter = unsafe { Lifter::from_raw(custom_arch_handle, il) };
 
-        match custom_arch.instruction_llil(data, addr, &mut lifter) {
-            Some((res_len, res_value)) => {
-                unsafe { *len = res_len };
-                res_value
-            }
-            None => false,
-        }
+                match custom_arch.instruction_llil(data, addr, &mut lifter) {
+                    Some((res_len, res_value)) => {
+                        unsafe { *len = res_len };
+                        res_value
+                    }
+                    None => false,
+                }
+            },
+            false
+        )
     }
 
     extern "C" fn cb_reg_name<A>(ctxt: *mut c_void, reg: u32) -> *mut c_char
@@ -20,11 +20,15 @@
 use binaryninjacore_sys::*;
 
 pub use binaryninjacore_sys::BNModificationStatus as ModificationStatus;
+pub use binaryninjacore_sys::BNStringType as StringType;
 
+use std::collections::HashSet;
+use std::mem;
 use std::ops;
 use std::os::raw::c_char;
 use std::ptr;
 use std::result;
+use std::sync::Mutex;
 
 use crate::architecture::Architecture;
 use crate::architecture::CoreArchitecture;
@@ -42,9 +46,12 @@ use crate::platform::Platform;
 use crate::section::{Section, SectionBuilder};
 use crate::segment::{Segment, SegmentBuilder};
 use crate::settings::Settings;
-use crate::symbol::{Symbol, SymbolType};
+use crate::symbol::{Binding, Symbol, SymbolType};
 use crate::tags::{Tag, TagType};
-use crate::types::{DataVariable, NamedTypeReference, QualifiedName, QualifiedNameAndType, Type};
+use crate::transform::Transform;
+use crate::types::{
+    Conf, DataVariable, NamedTypeReference, QualifiedName, QualifiedNameAndType, Type,
+};
 use crate::Endianness;
 
 use crate::rc::*;
@@ -127,6 +134,7 @@ pub trait BinaryViewBase: AsRef<BinaryView> {
         true
     }
 
+    /// Address of the entry point declared by the file format; see [`BinaryViewExt::entry_point_function`]
     fn entry_point(&self) -> u64;
     fn default_endianness(&self) -> Endianness;
     fn address_size(&self) -> usize;
@@ -164,6 +172,14 @@ pub trait BinaryViewExt: BinaryViewBase {
         unsafe { Ok(BinaryView::from_raw(handle)) }
     }
 
+    /// Moves the UI to `offset` in this view; fails when running headless or if there is no UI
+    /// for this file
+    ///
+    /// See [`FileMetadata::current_offset`] for where the user currently is.
+    fn navigate_to(&self, offset: u64) -> Result<()> {
+        self.file().navigate_to(self.type_name(), offset)
+    }
+
     /// Reads up to `len` bytes from address `offset`
     fn read_vec(&self, offset: u64, len: usize) -> Vec<u8> {
         let mut ret = Vec::with_capacity(len);
@@ -207,6 +223,36 @@ pub trait BinaryViewExt: BinaryViewBase {
         }
     }
 
+    /// Reads `range` in pieces of at most `chunk_size` bytes, passing each piece and its address to
+    /// `f`, and returns the number of bytes read
+    ///
+    /// Only one chunk is held in memory at a time, so large sections can be processed without
+    /// copying them whole as [`BinaryViewExt::read_vec`] would. Reading stops early at the first
+    /// address that can't be read.
+    fn read_chunks<F>(&self, range: ops::Range<u64>, chunk_size: usize, mut f: F) -> u64
+    where
+        F: FnMut(u64, &[u8]),
+    {
+        if chunk_size == 0 {
+            return 0;
+        }
+
+        let mut buf = vec![0u8; chunk_size];
+        let mut offset = range.start;
+        while offset < range.end {
+            let want = (range.end - offset).min(chunk_size as u64) as usize;
+            let read = self.read(&mut buf[..want], offset);
+            if read == 0 {
+                break;
+            }
+
+            f(offset, &buf[..read]);
+            offset += read as u64;
+        }
+
+        offset - range.start
+    }
+
     fn notify_data_written(&self, offset: u64, len: usize) {
         unsafe {
             BNNotifyDataWritten(self.as_ref().handle, offset, len);
@@ -225,6 +271,27 @@ pub trait BinaryViewExt: BinaryViewBase {
         }
     }
 
+    /// Shannon entropy, normalized to `0.0..=1.0`, of each `block_size` block of the `len` bytes
+    /// at `offset`
+    fn entropy(&self, offset: u64, len: usize, block_size: usize) -> Vec<f32> {
+        if block_size == 0 {
+            return vec![];
+        }
+
+        let mut result = vec![0.0; len / block_size + 1];
+        let count = unsafe {
+            BNGetEntropy(
+                self.as_ref().handle,
+                offset,
+                len,
+                block_size,
+                result.as_mut_ptr(),
+            )
+        };
+        result.truncate(count);
+        result
+    }
+
     fn offset_has_code_semantics(&self, offset: u64) -> bool {
         unsafe { BNIsOffsetCodeSemantics(self.as_ref().handle, offset) }
     }
@@ -249,6 +316,36 @@ pub trait BinaryViewExt: BinaryViewBase {
         }
     }
 
+    /// Suspends auto-analysis until the returned guard is dropped, at which point a single
+    /// analysis update is started for everything changed in the meantime
+    ///
+    /// Holds nest: analysis only resumes once every guard for the view has been dropped.
+    ///
+    /// ```
+    /// # use binaryninja::binaryview::BinaryViewExt;
+    /// # let bv = binaryninja::open_view("/bin/cat").unwrap();
+    /// {
+    ///     let _hold = bv.hold_analysis();
+    ///     // ... define many types, symbols, and functions ...
+    /// }
+    /// bv.update_analysis_and_wait();
+    /// ```
+    fn hold_analysis(&self) -> AnalysisHold {
+        let view = self.as_ref().to_owned();
+        let handle = view.handle as usize;
+
+        let mut holds = ANALYSIS_HOLDS.lock().unwrap();
+        match holds.iter_mut().find(|(h, _)| *h == handle) {
+            Some((_, count)) => *count += 1,
+            None => {
+                holds.push((handle, 1));
+                unsafe { BNSetAnalysisHold(view.handle, true) };
+            }
+        }
+
+        AnalysisHold { view }
+    }
+
     fn default_arch(&self) -> Option<CoreArchitecture> {
         unsafe {
             let raw = BNGetDefaultArchitecture(self.as_ref().handle);
@@ -395,6 +492,63 @@ pub trait BinaryViewExt: BinaryViewBase {
         }
     }
 
+    /// Symbols the view imports from other modules, both the import table entries
+    /// (`ImportAddress`) and the functions and data they resolve to
+    fn imports(&self) -> Vec<Ref<Symbol>> {
+        [
+            SymbolType::ImportAddress,
+            SymbolType::ImportedFunction,
+            SymbolType::ImportedData,
+        ]
+        .iter()
+        .flat_map(|&ty| {
+            self.symbols_of_type(ty)
+                .iter()
+                .map(|symbol| symbol.clone())
+                .collect::<Vec<_>>()
+        })
+        .collect()
+    }
+
+    /// Globally or weakly bound functions and data defined by the view
+    fn exports(&self) -> Vec<Ref<Symbol>> {
+        [SymbolType::Function, SymbolType::Data]
+            .iter()
+            .flat_map(|&ty| {
+                self.symbols_of_type(ty)
+                    .iter()
+                    .filter(|symbol| matches!(symbol.binding(), Binding::Global | Binding::Weak))
+                    .map(|symbol| symbol.clone())
+                    .collect::<Vec<_>>()
+            })
+            .collect()
+    }
+
+    /// Ranges of the view that have a relocation applied to them
+    fn relocation_ranges(&self) -> Array<RelocationRange> {
+        unsafe {
+            let mut count = 0;
+            let ranges = BNGetRelocationRanges(self.as_ref().handle, &mut count);
+
+            Array::new(ranges, count, ())
+        }
+    }
+
+    /// Relocated ranges that contain `addr`
+    fn relocation_ranges_at(&self, addr: u64) -> Array<RelocationRange> {
+        unsafe {
+            let mut count = 0;
+            let ranges = BNGetRelocationRangesAtAddress(self.as_ref().handle, addr, &mut count);
+
+            Array::new(ranges, count, ())
+        }
+    }
+
+    /// Whether any of the `size` bytes at `addr` are affected by a relocation
+    fn range_contains_relocation(&self, addr: u64, size: usize) -> bool {
+        unsafe { BNRangeContainsRelocation(self.as_ref().handle, addr, size) }
+    }
+
     fn define_auto_symbol(&self, sym: &Symbol) {
         unsafe {
             BNDefineAutoSymbol(self.as_ref().handle, sym.handle);
@@ -456,6 +610,13 @@ pub trait BinaryViewExt: BinaryViewBase {
         }
     }
 
+    fn define_user_data_var<'a, T: Into<Conf<&'a Type>>>(&self, addr: u64, ty: T) {
+        let mut ty = ty.into().into();
+        unsafe {
+            BNDefineUserDataVariable(self.as_ref().handle, addr, &mut ty);
+        }
+    }
+
     fn define_user_type<S: BnStrCompatible>(&self, name: S, type_obj: &Type) {
         unsafe {
             let mut qualified_name = QualifiedName::from(name);
@@ -613,6 +774,151 @@ pub trait BinaryViewExt: BinaryViewBase {
         }
     }
 
+    /// Strings found by the core's string analysis
+    fn strings(&self) -> Array<StringReference> {
+        unsafe {
+            let mut count = 0;
+            let strings = BNGetStrings(self.as_ref().handle, &mut count);
+
+            Array::new(strings, count, ())
+        }
+    }
+
+    fn strings_in_range(&self, range: ops::Range<u64>) -> Array<StringReference> {
+        unsafe {
+            let mut count = 0;
+            let strings = BNGetStringsInRange(
+                self.as_ref().handle,
+                range.start,
+                range.end.saturating_sub(range.start),
+                &mut count,
+            );
+
+            Array::new(strings, count, ())
+        }
+    }
+
+    /// Reads and decodes the contents of a string found by [`BinaryViewExt::strings`]
+    fn read_string(&self, string: &StringReference) -> Option<String> {
+        let data = self.read_vec(string.start(), string.len());
+        if data.len() != string.len() {
+            return None;
+        }
+
+        let big_endian = self.default_endianness() == Endianness::BigEndian;
+        match string.string_type() {
+            StringType::AsciiString | StringType::Utf8String => String::from_utf8(data).ok(),
+            StringType::Utf16String => {
+                let units: Vec<u16> = data
+                    .chunks_exact(2)
+                    .map(|c| {
+                        if big_endian {
+                            u16::from_be_bytes([c[0], c[1]])
+                        } else {
+                            u16::from_le_bytes([c[0], c[1]])
+                        }
+                    })
+                    .collect();
+                String::from_utf16(&units).ok()
+            }
+            StringType::Utf32String => data
+                .chunks_exact(4)
+                .map(|c| {
+                    let c = [c[0], c[1], c[2], c[3]];
+                    char::from_u32(if big_endian {
+                        u32::from_be_bytes(c)
+                    } else {
+                        u32::from_le_bytes(c)
+                    })
+                })
+                .collect(),
+        }
+    }
+
+    /// Defines user data variables for the strings in `range` and for tables of string pointers
+    /// in `range`, returning how many variables were defined
+    ///
+    /// Each string becomes a character array sized to include its terminator. Any run of at
+    /// least two consecutive, aligned, pointer-sized values that all point at the start of a
+    /// string (anywhere in the view) becomes an array of `char*`.
+    fn define_string_tables(&self, range: ops::Range<u64>) -> usize {
+        let mut defined = 0;
+
+        for string in &self.strings_in_range(range.clone()) {
+            let (element, width) = match string.string_type() {
+                StringType::AsciiString | StringType::Utf8String => (Type::char(), 1),
+                StringType::Utf16String => (Type::wide_char(2), 2),
+                StringType::Utf32String => (Type::wide_char(4), 4),
+            };
+
+            let mut count = (string.len() / width) as u64;
+            if let Some(end) = string.start().checked_add(string.len() as u64) {
+                let terminator = self.read_vec(end, width);
+                if terminator.len() == width && terminator.iter().all(|&b| b == 0) {
+                    count += 1;
+                }
+            }
+
+            let array = Type::array(&*element, count);
+            self.define_user_data_var(string.start(), &*array);
+            defined += 1;
+        }
+
+        let string_starts: HashSet<u64> = self.strings().iter().map(|s| s.start()).collect();
+        let pointer_size = self.address_size();
+        if pointer_size == 0 || pointer_size > 8 {
+            return defined;
+        }
+        let big_endian = self.default_endianness() == Endianness::BigEndian;
+        let char_pointer = Type::pointer_of_width(&*Type::char(), pointer_size, false, false, None);
+
+        let read_pointer = |addr: u64| -> Option<u64> {
+            let data = self.read_vec(addr, pointer_size);
+            if data.len() != pointer_size {
+                return None;
+            }
+
+            let mut bytes = [0u8; 8];
+            if big_endian {
+                bytes[8 - pointer_size..].copy_from_slice(&data);
+                Some(u64::from_be_bytes(bytes))
+            } else {
+                bytes[..pointer_size].copy_from_slice(&data);
+                Some(u64::from_le_bytes(bytes))
+            }
+        };
+
+        // Whether a whole pointer fits at `addr`, which also means advancing past it can't overflow
+        let step = pointer_size as u64;
+        let fits = |addr: u64| addr.checked_add(step).map_or(false, |end| end <= range.end);
+
+        let mut addr = match range.start.checked_add((step - range.start % step) % step) {
+            Some(addr) => addr,
+            None => return defined,
+        };
+        while fits(addr) {
+            let table_start = addr;
+            let mut entries = 0;
+            while fits(addr)
+                && read_pointer(addr).map_or(false, |target| string_starts.contains(&target))
+            {
+                entries += 1;
+                addr += step;
+            }
+
+            if entries >= 2 {
+                let table = Type::array(&*char_pointer, entries);
+                self.define_user_data_var(table_start, &*table);
+                defined += 1;
+            }
+            if entries == 0 {
+                addr += step;
+            }
+        }
+
+        defined
+    }
+
     fn sections_at(&self, addr: u64) -> Array<Section> {
         unsafe {
             let mut count = 0;
@@ -685,6 +991,10 @@ pub trait BinaryViewExt: BinaryViewBase {
         unsafe { BNHasFunctions(self.as_ref().handle) }
     }
 
+    /// The function analysis created at [`BinaryViewBase::entry_point`], if any
+    ///
+    /// Additional entry points registered with [`BinaryViewExt::add_entry_point`] are not tracked
+    /// separately by this version of the core; they appear in [`BinaryViewExt::functions`].
     fn entry_point_function(&self) -> Result<Ref<Function>> {
         unsafe {
             let func = BNGetAnalysisEntryPoint(self.as_ref().handle);
@@ -706,6 +1016,25 @@ pub trait BinaryViewExt: BinaryViewBase {
         }
     }
 
+    /// Runs `f` on every function across the rayon thread pool, returning the results in the same
+    /// order as [`BinaryViewExt::functions`]
+    ///
+    /// The function list is snapshotted up front, so no view-wide lock is held while `f` runs and
+    /// functions added during the call are not visited. `f` must not wait on analysis
+    /// (`update_analysis_and_wait`, etc.): analysis may itself be waiting on the pool's threads.
+    #[cfg(feature = "rayon")]
+    fn par_functions<T, F>(&self, f: F) -> Vec<T>
+    where
+        T: Send,
+        F: Fn(&Function) -> T + Sync + Send,
+    {
+        use rayon::prelude::*;
+
+        let functions: Vec<Ref<Function>> =
+            self.functions().iter().map(|func| func.clone()).collect();
+        functions.par_iter().map(|func| f(func)).collect()
+    }
+
     /// List of functions *starting* at `addr`
     fn functions_at(&self, addr: u64) -> Array<Function> {
         unsafe {
@@ -766,6 +1095,60 @@ pub trait BinaryViewExt: BinaryViewBase {
         }
     }
 
+    /// Contents of the section named `name`; see [`BinaryViewExt::section_contents`]
+    ///
+    /// Fails if there is no such section, it can't be read, or it can't be decompressed.
+    fn section_data<S: BnStrCompatible>(&self, name: S) -> Result<DataBuffer> {
+        self.section_contents(&self.section_by_name(name)?)
+    }
+
+    /// Contents of `section`, or an empty buffer if the section is empty
+    ///
+    /// Compressed debug sections of ELF views are decompressed: `.zdebug_*` sections (`ZLIB` magic
+    /// followed by the big-endian uncompressed size), and `.debug_*` sections whose data starts with
+    /// an `Elf_Chdr` (`SHF_COMPRESSED`). Section flags aren't available, so the latter is recognized
+    /// from the header and the magic of the stream that follows it. Other sections are returned
+    /// as-is.
+    ///
+    /// Fails if the section can't be read, can't be decompressed, or uses a compression other than
+    /// zlib (such as `ELFCOMPRESS_ZSTD`).
+    fn section_contents(&self, section: &Section) -> Result<DataBuffer> {
+        if section.len() == 0 {
+            return DataBuffer::new(&[]);
+        }
+
+        let data = self.read_buffer(section.start(), section.len())?;
+        if self.type_name().as_str() != "ELF" {
+            return Ok(data);
+        }
+
+        let name = section.name();
+        let name = name.as_str();
+        let compressed = if name.starts_with(".zdebug_") {
+            zdebug_payload(data.get_data())
+        } else if name.starts_with(".debug_") {
+            compressed_section_payload(
+                data.get_data(),
+                self.address_size(),
+                self.default_endianness(),
+            )
+        } else {
+            None
+        };
+
+        match compressed {
+            Some((ELFCOMPRESS_ZLIB, payload, size)) => {
+                let decompressed = Transform::by_name("Zlib")?.decode(payload)?;
+                if decompressed.len() as u64 != size {
+                    return Err(());
+                }
+                DataBuffer::new(&decompressed)
+            }
+            Some(_) => Err(()),
+            None => Ok(data),
+        }
+    }
+
     fn debug_info(&self) -> Ref<DebugInfo> {
         unsafe { DebugInfo::from_raw(BNGetDebugInfo(self.as_ref().handle)) }
     }
@@ -789,6 +1172,21 @@ pub trait BinaryViewExt: BinaryViewBase {
         }
     }
 
+    /// Shows `contents` as a report in the UI, or `plaintext` when running headless
+    fn show_markdown_report<S: BnStrCompatible>(&self, title: S, contents: S, plaintext: S) {
+        let title = title.into_bytes_with_nul();
+        let contents = contents.into_bytes_with_nul();
+        let plaintext = plaintext.into_bytes_with_nul();
+        unsafe {
+            BNShowMarkdownReport(
+                self.as_ref().handle,
+                title.as_ref().as_ptr() as *mut _,
+                contents.as_ref().as_ptr() as *mut _,
+                plaintext.as_ref().as_ptr() as *mut _,
+            );
+        }
+    }
+
     fn load_settings<S: BnStrCompatible>(&self, view_type_name: S) -> Result<Ref<Settings>> {
         let view_type_name = view_type_name.into_bytes_with_nul();
         let settings_handle = unsafe {
@@ -985,6 +1383,42 @@ pub trait BinaryViewExt: BinaryViewBase {
             )
         };
     }
+
+    /// Evaluates an address expression the same way the UI's "Go to Address" dialog does
+    ///
+    /// Expressions can use symbol and section names, `$here` (the value of `here`), `$start`,
+    /// `$end`, arithmetic and comparison operators, and `[addr]`-style dereferences. Unprefixed
+    /// numbers are hexadecimal; use `0n` for decimal.
+    fn parse_expression<S: BnStrCompatible>(
+        &self,
+        expression: S,
+        here: u64,
+    ) -> result::Result<u64, String> {
+        let expression = expression.into_bytes_with_nul();
+        let mut offset = 0;
+        let mut error = ptr::null_mut();
+
+        unsafe {
+            if BNParseExpression(
+                self.as_ref().handle,
+                expression.as_ref().as_ptr() as *const c_char,
+                &mut offset,
+                here,
+                &mut error,
+            ) {
+                Ok(offset)
+            } else {
+                let message = if error.is_null() {
+                    String::from("Failed to parse expression")
+                } else {
+                    let message = BnStr::from_raw(error).to_string();
+                    BNFreeParseError(error);
+                    message
+                };
+                Err(message)
+            }
+        }
+    }
 }
 
 impl<T: BinaryViewBase> BinaryViewExt for T {}
@@ -1144,3 +1578,182 @@ impl ToOwned for BinaryView {
 
 unsafe impl Send for BinaryView {}
 unsafe impl Sync for BinaryView {}
+
+////////////////////
+// StringReference
+
+#[repr(transparent)]
+pub struct StringReference(pub(crate) BNStringReference);
+
+impl StringReference {
+    pub fn string_type(&self) -> StringType {
+        self.0.type_
+    }
+
+    pub fn start(&self) -> u64 {
+        self.0.start
+    }
+
+    /// Length of the string in bytes
+    pub fn len(&self) -> usize {
+        self.0.length
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.length == 0
+    }
+}
+
+impl CoreArrayProvider for StringReference {
+    type Raw = BNStringReference;
+    type Context = ();
+}
+
+unsafe impl CoreOwnedArrayProvider for StringReference {
+    unsafe fn free(raw: *mut Self::Raw, _count: usize, _context: &Self::Context) {
+        BNFreeStringReferenceList(raw);
+    }
+}
+
+unsafe impl<'a> CoreArrayWrapper<'a> for StringReference {
+    type Wrapped = &'a StringReference;
+
+    unsafe fn wrap_raw(raw: &'a Self::Raw, _context: &'a Self::Context) -> Self::Wrapped {
+        mem::transmute(raw)
+    }
+}
+
+////////////////////
+// AnalysisHold
+
+/// Keeps auto-analysis of a view on hold until dropped; see [`BinaryViewExt::hold_analysis`]
+#[must_use = "analysis resumes as soon as the hold is dropped"]
+pub struct AnalysisHold {
+    view: Ref<BinaryView>,
+}
+
+// Outstanding holds per view handle; the core's hold is a flag, so nesting is tracked here
+static ANALYSIS_HOLDS: Mutex<Vec<(usize, usize)>> = Mutex::new(Vec::new());
+
+impl Drop for AnalysisHold {
+    fn drop(&mut self) {
+        let handle = self.view.handle as usize;
+
+        let mut holds = ANALYSIS_HOLDS.lock().unwrap();
+        let index = match holds.iter().position(|(h, _)| *h == handle) {
+            Some(index) => index,
+            None => return,
+        };
+        holds[index].1 -= 1;
+        if holds[index].1 > 0 {
+            return;
+        }
+        holds.swap_remove(index);
+        drop(holds);
+
+        unsafe { BNSetAnalysisHold(self.view.handle, false) };
+        self.view.update_analysis();
+    }
+}
+
+////////////////////
+// RelocationRange
+
+#[repr(transparent)]
+pub struct RelocationRange(pub(crate) BNRange);
+
+impl RelocationRange {
+    pub fn start(&self) -> u64 {
+        self.0.start
+    }
+
+    pub fn end(&self) -> u64 {
+        self.0.end
+    }
+}
+
+impl CoreArrayProvider for RelocationRange {
+    type Raw = BNRange;
+    type Context = ();
+}
+
+unsafe impl CoreOwnedArrayProvider for RelocationRange {
+    unsafe fn free(raw: *mut Self::Raw, _count: usize, _context: &Self::Context) {
+        BNFreeRelocationRanges(raw);
+    }
+}
+
+unsafe impl<'a> CoreArrayWrapper<'a> for RelocationRange {
+    type Wrapped = &'a RelocationRange;
+
+    unsafe fn wrap_raw(raw: &'a Self::Raw, _context: &'a Self::Context) -> Self::Wrapped {
+        mem::transmute(raw)
+    }
+}
+
+////////////////////
+// Compressed sections
+
+const ELFCOMPRESS_ZLIB: u32 = 1;
+const ELFCOMPRESS_ZSTD: u32 = 2;
+
+const ZSTD_MAGIC: [u8; 4] = [0x28, 0xb5, 0x2f, 0xfd];
+
+fn is_zlib_stream(data: &[u8]) -> bool {
+    match data {
+        [cmf, flg, ..] => cmf & 0x0f == 8 && (((*cmf as u16) << 8) | *flg as u16) % 31 == 0,
+        _ => false,
+    }
+}
+
+// `.zdebug_*`: "ZLIB", the uncompressed size as a big-endian u64, then the zlib stream
+fn zdebug_payload(data: &[u8]) -> Option<(u32, &[u8], u64)> {
+    let size = u64::from_be_bytes(data.strip_prefix(b"ZLIB")?.get(..8)?.try_into().ok()?);
+    let payload = &data[12..];
+    if is_zlib_stream(payload) {
+        Some((ELFCOMPRESS_ZLIB, payload, size))
+    } else {
+        None
+    }
+}
+
+// `SHF_COMPRESSED`: an `Elf32_Chdr` or `Elf64_Chdr` in the file's byte order, then the compressed
+// stream. Returns the compression type along with the stream and its uncompressed size.
+fn compressed_section_payload(
+    data: &[u8],
+    address_size: usize,
+    endianness: Endianness,
+) -> Option<(u32, &[u8], u64)> {
+    let read = |offset: usize, len: usize| -> Option<u64> {
+        let bytes = data.get(offset..offset + len)?;
+        Some(bytes.iter().enumerate().fold(0, |acc, (i, b)| {
+            let shift = match endianness {
+                Endianness::LittleEndian => i,
+                Endianness::BigEndian => len - 1 - i,
+            } * 8;
+            acc | ((*b as u64) << shift)
+        }))
+    };
+
+    let (size, align, header_len) = match address_size {
+        8 => (read(8, 8)?, read(16, 8)?, 24),
+        4 => (read(4, 4)?, read(8, 4)?, 12),
+        _ => return None,
+    };
+    if !align.is_power_of_two() {
+        return None;
+    }
+
+    let payload = data.get(header_len..)?;
+    let compression = read(0, 4)? as u32;
+    let recognized = match compression {
+        ELFCOMPRESS_ZLIB => is_zlib_stream(payload),
+        ELFCOMPRESS_ZSTD => payload.starts_with(&ZSTD_MAGIC),
+        _ => false,
+    };
+    if recognized {
+        Some((compression, payload, size))
+    } else {
+        None
+    }
+}
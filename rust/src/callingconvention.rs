@@ -446,6 +446,22 @@ impl<A: Architecture> CallingConvention<A> {
         unsafe { BnString::from_raw(BNGetCallingConventionName(self.handle)) }
     }
 
+    unsafe fn registers_from_raw(&self, regs: *mut u32, count: usize) -> Vec<A::Register> {
+        let arch = self.arch_handle.borrow();
+
+        let res = slice::from_raw_parts(regs, count)
+            .iter()
+            .map(|&r| {
+                arch.register_from_id(r)
+                    .expect("bad reg id from CallingConvention")
+            })
+            .collect();
+
+        BNFreeRegisterList(regs);
+
+        res
+    }
+
     pub fn variables_for_parameters<S: Clone + BnStrCompatible>(
         &self,
         params: &[FunctionParameter<S>],
@@ -536,19 +552,7 @@ impl<A: Architecture> CallingConventionBase for CallingConvention<A> {
         unsafe {
             let mut count = 0;
             let regs = BNGetCallerSavedRegisters(self.handle, &mut count);
-            let arch = self.arch_handle.borrow();
-
-            let res = slice::from_raw_parts(regs, count)
-                .iter()
-                .map(|&r| {
-                    arch.register_from_id(r)
-                        .expect("bad reg id from CallingConvention")
-                })
-                .collect();
-
-            BNFreeRegisterList(regs);
-
-            res
+            self.registers_from_raw(regs, count)
         }
     }
 
@@ -556,28 +560,24 @@ impl<A: Architecture> CallingConventionBase for CallingConvention<A> {
         unsafe {
             let mut count = 0;
             let regs = BNGetCalleeSavedRegisters(self.handle, &mut count);
-            let arch = self.arch_handle.borrow();
-
-            let res = slice::from_raw_parts(regs, count)
-                .iter()
-                .map(|&r| {
-                    arch.register_from_id(r)
-                        .expect("bad reg id from CallingConvention")
-                })
-                .collect();
-
-            BNFreeRegisterList(regs);
-
-            res
+            self.registers_from_raw(regs, count)
         }
     }
 
     fn int_arg_registers(&self) -> Vec<A::Register> {
-        Vec::new()
+        unsafe {
+            let mut count = 0;
+            let regs = BNGetIntegerArgumentRegisters(self.handle, &mut count);
+            self.registers_from_raw(regs, count)
+        }
     }
 
     fn float_arg_registers(&self) -> Vec<A::Register> {
-        Vec::new()
+        unsafe {
+            let mut count = 0;
+            let regs = BNGetFloatArgumentRegisters(self.handle, &mut count);
+            self.registers_from_raw(regs, count)
+        }
     }
 
     fn arg_registers_shared_index(&self) -> bool {
@@ -593,7 +593,7 @@ impl<A: Architecture> CallingConventionBase for CallingConvention<A> {
     }
 
     fn is_eligible_for_heuristics(&self) -> bool {
-        false
+        unsafe { BNIsEligibleForHeuristics(self.handle) }
     }
 
     fn return_int_reg(&self) -> Option<A::Register> {
@@ -625,7 +625,11 @@ impl<A: Architecture> CallingConventionBase for CallingConvention<A> {
     }
 
     fn implicitly_defined_registers(&self) -> Vec<A::Register> {
-        Vec::new()
+        unsafe {
+            let mut count = 0;
+            let regs = BNGetImplicitlyDefinedRegisters(self.handle, &mut count);
+            self.registers_from_raw(regs, count)
+        }
     }
 
     fn are_argument_registers_used_for_var_args(&self) -> bool {
@@ -16,10 +16,11 @@
 
 use binaryninjacore_sys::*;
 
+use std::os::raw::c_void;
 use std::ptr;
 use std::slice;
 
-pub struct DataBuffer(*mut BNDataBuffer);
+pub struct DataBuffer(pub(crate) *mut BNDataBuffer);
 
 impl DataBuffer {
     pub(crate) fn from_raw(raw: *mut BNDataBuffer) -> Self {
@@ -47,14 +48,14 @@ impl DataBuffer {
         unsafe { BNGetDataBufferLength(self.0) == 0 }
     }
 
-    // pub fn new(data: ?, len: usize) -> Result<Self> {
-    //   let read_buffer = unsafe { BNCreateDataBuffer(data, len) };
-    //   if read_buffer.is_null() {
-    //     Err(())
-    //   } else {
-    //     Ok(DataBuffer::from_raw(read_buffer))
-    //   }
-    // }
+    pub fn new(data: &[u8]) -> Result<Self, ()> {
+        let buffer = unsafe { BNCreateDataBuffer(data.as_ptr() as *const c_void, data.len()) };
+        if buffer.is_null() {
+            Err(())
+        } else {
+            Ok(DataBuffer::from_raw(buffer))
+        }
+    }
 }
 
 // TODO : delete this
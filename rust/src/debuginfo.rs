@@ -67,14 +67,16 @@
 use binaryninjacore_sys::*;
 
 use crate::{
-    binaryview::BinaryView,
+    binaryview::{BinaryView, BinaryViewExt},
+    interaction::{show_message_box, MessageBoxButtonResult, MessageBoxButtonSet, MessageBoxIcon},
     platform::Platform,
     rc::*,
     string::{raw_to_string, BnStrCompatible, BnString},
-    types::{DataVariableAndName, NameAndType, Type},
+    symbol::{Symbol, SymbolType},
+    types::{Conf, DataVariableAndName, NameAndType, Type, TypeBuilder, TypeClass},
 };
 
-use std::{hash::Hash, mem, os::raw::c_void, ptr, slice};
+use std::{collections::HashSet, hash::Hash, mem, os::raw::c_void, ptr, slice};
 
 struct ProgressContext(Option<Box<dyn Fn(usize, usize) -> Result<(), ()>>>);
 
@@ -175,6 +177,63 @@ impl DebugInfoParser {
         Some(unsafe { DebugInfo::from_raw(info) })
     }
 
+    /// Parses `view` with each of `parsers` that is valid for it into a single `DebugInfo`
+    ///
+    /// `parsers` are ordered from highest to lowest priority. Where parsers disagree, only the
+    /// highest priority parser's entry is kept: types conflict by name, and functions and data
+    /// variables by address. Lower priority parsers still contribute everything that doesn't
+    /// conflict. Parsers listed more than once only run at their highest priority.
+    pub fn parse_debug_info_prioritized(
+        view: &BinaryView,
+        parsers: &[Ref<DebugInfoParser>],
+    ) -> Option<Ref<DebugInfo>> {
+        let mut debug_info: Option<Ref<DebugInfo>> = None;
+        let mut parser_names = vec![];
+        let mut seen_parsers = HashSet::new();
+        for parser in parsers.iter().filter(|p| p.is_valid_for_view(view)) {
+            // A parser listed twice would otherwise find all of its own entries already claimed
+            if !seen_parsers.insert(parser.name().to_string()) {
+                continue;
+            }
+            if let Some(info) = parser.parse_debug_info(view, debug_info.as_deref(), None) {
+                debug_info = Some(info);
+                parser_names.push(parser.name().to_string());
+            }
+        }
+        let debug_info = debug_info?;
+
+        let mut type_names = HashSet::new();
+        let mut function_addresses = HashSet::new();
+        let mut data_variable_addresses = HashSet::new();
+        for parser_name in &parser_names {
+            for t in debug_info.types_by_name(parser_name.as_str()) {
+                if !type_names.insert(t.name.clone()) {
+                    debug_info.remove_type_by_name(parser_name.as_str(), t.name.as_str());
+                }
+            }
+
+            // Removing by index shifts later functions down, so remove from the back
+            let functions = debug_info.functions_by_name(parser_name.as_str());
+            for (index, function) in functions.iter().enumerate().rev() {
+                if function.address != 0 && function_addresses.contains(&function.address) {
+                    debug_info.remove_function_by_index(parser_name.as_str(), index);
+                }
+            }
+            function_addresses.extend(functions.iter().map(|f| f.address).filter(|&a| a != 0));
+
+            for data_variable in debug_info.data_variables_by_name(parser_name.as_str()) {
+                if !data_variable_addresses.insert(data_variable.address) {
+                    debug_info.remove_data_variable_by_address(
+                        parser_name.as_str(),
+                        data_variable.address,
+                    );
+                }
+            }
+        }
+
+        Some(debug_info)
+    }
+
     // Registers a DebugInfoParser. See `binaryninja::debuginfo::DebugInfoParser` for more details.
     pub fn register<S, C>(name: S, parser_callbacks: C) -> Ref<Self>
     where
@@ -185,12 +244,16 @@ impl DebugInfoParser {
         where
             C: CustomDebugInfoParser,
         {
-            ffi_wrap!("CustomDebugInfoParser::is_valid", unsafe {
-                let cmd = &*(ctxt as *const C);
-                let view = BinaryView::from_raw(view);
+            ffi_wrap!(
+                "CustomDebugInfoParser::is_valid",
+                unsafe {
+                    let cmd = &*(ctxt as *const C);
+                    let view = BinaryView::from_raw(view);
 
-                cmd.is_valid(&view)
-            })
+                    cmd.is_valid(&view)
+                },
+                false
+            )
         }
 
         extern "C" fn cb_parse_info<C>(
@@ -203,26 +266,30 @@ impl DebugInfoParser {
         where
             C: CustomDebugInfoParser,
         {
-            ffi_wrap!("CustomDebugInfoParser::parse_info", unsafe {
-                let cmd = &*(ctxt as *const C);
-                let view = BinaryView::from_raw(view);
-                let mut debug_info = DebugInfo::from_raw(debug_info);
-
-                cmd.parse_info(
-                    &mut debug_info,
-                    &view,
-                    Box::new(move |cur: usize, max: usize| match progress {
-                        Some(func) => {
-                            if func(progress_ctxt, cur, max) {
-                                Ok(())
-                            } else {
-                                Err(())
+            ffi_wrap!(
+                "CustomDebugInfoParser::parse_info",
+                unsafe {
+                    let cmd = &*(ctxt as *const C);
+                    let view = BinaryView::from_raw(view);
+                    let mut debug_info = DebugInfo::from_raw(debug_info);
+
+                    cmd.parse_info(
+                        &mut debug_info,
+                        &view,
+                        Box::new(move |cur: usize, max: usize| match progress {
+                            Some(func) => {
+                                if func(progress_ctxt, cur, max) {
+                                    Ok(())
+                                } else {
+                                    Err(())
+                                }
                             }
-                        }
-                        _ => Ok(()),
-                    }),
-                )
-            })
+                            _ => Ok(()),
+                        }),
+                    )
+                },
+                false
+            )
         }
 
         let name = name.into_bytes_with_nul();
@@ -330,6 +397,55 @@ impl<S: BnStrCompatible> DebugFunctionInfo<S> {
             platform,
         }
     }
+
+    pub fn short_name(&self) -> Option<&S> {
+        self.short_name.as_ref()
+    }
+
+    pub fn full_name(&self) -> Option<&S> {
+        self.full_name.as_ref()
+    }
+
+    pub fn raw_name(&self) -> Option<&S> {
+        self.raw_name.as_ref()
+    }
+
+    /// Address of the function, or 0 if the debug info doesn't know it
+    pub fn address(&self) -> u64 {
+        self.address
+    }
+
+    pub fn function_type(&self) -> Option<&Type> {
+        self.type_.as_deref()
+    }
+
+    pub fn platform(&self) -> Option<&Platform> {
+        self.platform.as_deref()
+    }
+
+    pub fn return_type(&self) -> Option<Conf<Ref<Type>>> {
+        self.type_.as_ref()?.return_value().ok()
+    }
+
+    /// Replaces the return type of the function's type, along with the confidence it is applied with
+    ///
+    /// When this conflicts with the return type analysis determines, whichever has the higher confidence
+    /// is kept, so pass a confidence below `max_confidence()` to let analysis override a return type that
+    /// the debug info is unsure of. Fails if no function type has been provided.
+    pub fn set_return_type<'a, T: Into<Conf<&'a Type>>>(
+        &mut self,
+        return_type: T,
+    ) -> Result<(), ()> {
+        let function_type = match &self.type_ {
+            Some(t) if t.type_class() == TypeClass::FunctionTypeClass => t,
+            _ => return Err(()),
+        };
+
+        let builder = TypeBuilder::new(function_type);
+        builder.set_child_type(return_type);
+        self.type_ = Some(builder.finalize());
+        Ok(())
+    }
 }
 
 ///////////////
@@ -486,6 +602,194 @@ impl DebugInfo {
         result
     }
 
+    /// Applies this debug info to `view` as user annotations, with every address shifted by `delta`
+    ///
+    /// `BinaryViewExt::apply_debug_info` assumes the debug info describes the view it is applied to.
+    /// This is for debug info parsed from a different view (an unstripped build, or a dSYM) whose
+    /// image base differs from the stripped view by `delta`. Functions are created where missing.
+    pub fn apply_to_view_with_delta(&self, view: &BinaryView, delta: i64) {
+        let rebase = |address: u64| address.wrapping_add(delta as u64);
+
+        for t in self.types() {
+            view.define_user_type(t.name.as_str(), &t.type_with_confidence().contents);
+        }
+
+        for function in self.functions() {
+            // Functions without an address are only useful for querying
+            if function.address == 0 {
+                continue;
+            }
+            let address = rebase(function.address);
+
+            let platform = match function
+                .platform
+                .clone()
+                .or_else(|| view.default_platform())
+            {
+                Some(platform) => platform,
+                None => continue,
+            };
+            if view.functions_at(address).is_empty() {
+                view.create_user_function(&platform, address);
+            }
+
+            if let Some(t) = &function.type_ {
+                for existing in &view.functions_at(address) {
+                    existing.set_user_type(t);
+                }
+            }
+
+            let raw_name = function
+                .raw_name
+                .as_ref()
+                .or(function.full_name.as_ref())
+                .or(function.short_name.as_ref());
+            if let Some(raw_name) = raw_name {
+                let mut builder = Symbol::builder(SymbolType::Function, raw_name.as_str(), address);
+                if let Some(full_name) = &function.full_name {
+                    builder = builder.full_name(full_name.as_str());
+                }
+                if let Some(short_name) = &function.short_name {
+                    builder = builder.short_name(short_name.as_str());
+                }
+                view.define_user_symbol(&builder.create());
+            }
+        }
+
+        for data_variable in self.data_variables() {
+            let address = rebase(data_variable.address);
+            view.define_user_data_var(address, &data_variable.t);
+            if !data_variable.name.is_empty() {
+                let symbol =
+                    Symbol::builder(SymbolType::Data, data_variable.name.as_str(), address)
+                        .create();
+                view.define_user_symbol(&symbol);
+            }
+        }
+    }
+
+    /// Removes every function for which `exclude` returns true, from all parsers, returning how
+    /// many were removed
+    ///
+    /// Call this before applying the debug info to skip functions that aren't wanted, such as
+    /// thousands of template instantiations:
+    /// ```
+    /// # use binaryninja::debuginfo::DebugInfo;
+    /// # fn example(debug_info: &DebugInfo) {
+    /// debug_info.remove_functions_where(|f| {
+    ///     f.full_name().map_or(false, |name| name.starts_with("std::"))
+    /// });
+    /// # }
+    /// ```
+    pub fn remove_functions_where<F>(&self, exclude: F) -> usize
+    where
+        F: Fn(&DebugFunctionInfo<String>) -> bool,
+    {
+        let mut removed = 0;
+        for parser_name in &self.parser_names() {
+            let functions = self.functions_by_name(parser_name.as_str());
+
+            // Removing by index shifts later functions down, so remove from the back
+            for (index, function) in functions.iter().enumerate().rev() {
+                if exclude(function) && self.remove_function_by_index(parser_name.as_str(), index) {
+                    removed += 1;
+                }
+            }
+        }
+        removed
+    }
+
+    /// Names of the parsers that have contributed to this debug info
+    pub fn parser_names(&self) -> Array<BnString> {
+        let mut count: usize = 0;
+
+        unsafe {
+            let names = BNGetDebugParserNames(self.handle, &mut count);
+            Array::new(names, count, ())
+        }
+    }
+
+    /// A markdown summary of what each parser contributed, with the first `samples` entries of each kind
+    ///
+    /// Names are escaped, so debug info can't add links, images, or other markup to the summary.
+    pub fn summary_markdown(&self, samples: usize) -> String {
+        let mut summary = String::from("# Debug Info\n");
+
+        for parser_name in &self.parser_names() {
+            let types = self.types_by_name(parser_name.as_str());
+            let functions = self.functions_by_name(parser_name.as_str());
+            let data_variables = self.data_variables_by_name(parser_name.as_str());
+
+            summary.push_str(&format!(
+                "\n## {}\n\n{} types, {} functions, {} data variables\n",
+                markdown_escape(parser_name.as_str()),
+                types.len(),
+                functions.len(),
+                data_variables.len()
+            ));
+
+            if !types.is_empty() {
+                summary.push_str("\n### Types\n\n");
+                for t in types.iter().take(samples) {
+                    summary.push_str(&format!("* {}\n", markdown_escape(&t.name)));
+                }
+            }
+
+            if !functions.is_empty() {
+                summary.push_str("\n### Functions\n\n");
+                for function in functions.iter().take(samples) {
+                    let name = function
+                        .full_name
+                        .as_deref()
+                        .or(function.raw_name.as_deref())
+                        .or(function.short_name.as_deref())
+                        .unwrap_or("<unnamed>");
+                    summary.push_str(&format!(
+                        "* `{:#x}` {}\n",
+                        function.address,
+                        markdown_escape(name)
+                    ));
+                }
+            }
+
+            if !data_variables.is_empty() {
+                summary.push_str("\n### Data Variables\n\n");
+                for data_variable in data_variables.iter().take(samples) {
+                    summary.push_str(&format!(
+                        "* `{:#x}` {}\n",
+                        data_variable.address,
+                        markdown_escape(&data_variable.name)
+                    ));
+                }
+            }
+        }
+
+        summary
+    }
+
+    /// Shows a [summary](DebugInfo::summary_markdown) of this debug info and only applies it to `view`
+    /// if the user confirms, returning whether it was applied
+    ///
+    /// Useful when the debug info comes from a file that isn't trusted to be accurate. Without a UI
+    /// the confirmation can't be answered, so nothing is applied and this returns `false`.
+    pub fn apply_with_confirmation(&self, view: &BinaryView) -> bool {
+        let summary = self.summary_markdown(20);
+        view.show_markdown_report("Debug Info Preview", summary.as_str(), summary.as_str());
+
+        let response = show_message_box(
+            "Apply Debug Info",
+            "Apply the debug info shown in the preview to this view?",
+            MessageBoxButtonSet::YesNoButtonSet,
+            MessageBoxIcon::QuestionIcon,
+        );
+        if response != MessageBoxButtonResult::YesButton {
+            return false;
+        }
+
+        view.apply_debug_info(self);
+        true
+    }
+
     /// May return nullptr
     pub fn type_by_name<S: BnStrCompatible>(&self, parser_name: S, name: S) -> Option<Ref<Type>> {
         let parser_name = parser_name.into_bytes_with_nul();
@@ -829,6 +1133,25 @@ impl ToOwned for DebugInfo {
     }
 }
 
+// Backslash-escapes markdown punctuation and entity-encodes HTML, so untrusted names render as plain text
+fn markdown_escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '\n' | '\r' => escaped.push(' '),
+            c if c.is_ascii_punctuation() => {
+                escaped.push('\\');
+                escaped.push(c);
+            }
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
 ////////////////////////////
 //  CustomDebugInfoParser
 
@@ -22,4 +22,19 @@ macro_rules! ffi_wrap {
             process::abort()
         })
     }};
+    // Callbacks that can report failure to the core return `$fail` instead of aborting, but only
+    // when the plugin has opted in with `binaryninja::panic::install_hook`
+    ($n:expr, $b:expr, $fail:expr) => {{
+        use std::panic;
+        use std::process;
+
+        panic::catch_unwind(|| $b).unwrap_or_else(|_| {
+            error!("ffi callback caught panic: {}", $n);
+            if crate::panic::recovery_enabled() {
+                $fail
+            } else {
+                process::abort()
+            }
+        })
+    }};
 }
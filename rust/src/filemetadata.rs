@@ -23,6 +23,7 @@ use binaryninjacore_sys::{
     BNFreeFileMetadata,
     BNGetCurrentOffset,
     BNGetCurrentView,
+    BNGetExistingViews,
     BNGetFileViewOfType,
     BNGetFilename,
     BNIsAnalysisChanged,
@@ -44,10 +45,12 @@ use binaryninjacore_sys::{
 };
 
 use crate::binaryview::BinaryView;
+use crate::custombinaryview::{BinaryViewType, BinaryViewTypeExt};
 
 use crate::rc::*;
 use crate::string::*;
 
+use std::ffi::CStr;
 use std::ptr;
 
 #[derive(PartialEq, Eq, Hash)]
@@ -182,6 +185,41 @@ impl FileMetadata {
         }
     }
 
+    /// Names of the view types that are currently open in (or, for a database, stored in) this file
+    pub fn existing_views(&self) -> Array<BnString> {
+        let mut count = 0;
+
+        unsafe {
+            let views = BNGetExistingViews(self.handle, &mut count);
+            Array::new(views, count, ())
+        }
+    }
+
+    /// The unparsed view of the file's contents, which every other view type is created on top of
+    pub fn raw_view(&self) -> Result<Ref<BinaryView>, ()> {
+        self.get_view_of_type("Raw")
+    }
+
+    /// View types that are able to parse this file, whether or not they are open yet
+    pub fn available_view_types(&self) -> Result<Array<BinaryViewType>, ()> {
+        Ok(BinaryViewType::list_valid_types_for(&self.raw_view()?))
+    }
+
+    /// Returns the open view of type `view`, creating it from the raw view if it isn't open yet
+    pub fn view_of_type_or_create<S: BnStrCompatible>(
+        &self,
+        view: S,
+    ) -> Result<Ref<BinaryView>, ()> {
+        let view = view.into_bytes_with_nul();
+        let view = CStr::from_bytes_with_nul(view.as_ref()).map_err(|_| ())?;
+
+        if let Ok(existing) = self.get_view_of_type(view) {
+            return Ok(existing);
+        }
+
+        BinaryViewType::by_name(view)?.open(&self.raw_view()?)
+    }
+
     pub fn create_database<S: BnStrCompatible>(&self, filename: S) -> bool {
         let filename = filename.into_bytes_with_nul();
         let raw = "Raw".into_bytes_with_nul();
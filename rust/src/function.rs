@@ -17,7 +17,7 @@ use binaryninjacore_sys::*;
 use crate::rc::*;
 use crate::string::*;
 use crate::{
-    architecture::CoreArchitecture,
+    architecture::{CoreArchitecture, Register},
     basicblock::{BasicBlock, BlockContext},
     binaryview::{BinaryView, BinaryViewExt},
     llil,
@@ -26,7 +26,9 @@ use crate::{
     types::{Conf, Type},
 };
 
-use std::{fmt, mem};
+use std::{fmt, mem, ptr};
+
+pub use binaryninjacore_sys::BNRegisterValueType as RegisterValueType;
 
 pub struct Location {
     pub arch: Option<CoreArchitecture>,
@@ -253,11 +255,49 @@ impl Function {
         unsafe { Type::ref_from_raw(BNGetFunctionType(self.handle)) }
     }
 
-    pub fn set_user_type(&self, t: Type) {
+    pub fn set_user_type(&self, t: &Type) {
         unsafe {
             BNSetFunctionUserType(self.handle, t.handle);
         }
     }
+
+    /// What data flow knows about `reg` just before the instruction at `addr` executes
+    pub fn register_value_at<R: Register>(&self, addr: u64, reg: R) -> RegisterValue {
+        unsafe {
+            BNGetRegisterValueAtInstruction(self.handle, self.arch().0, addr, reg.id()).into()
+        }
+    }
+
+    /// What data flow knows about parameter `index` of the call at `addr`
+    ///
+    /// Pass the callee's type as `function_type` if it is known; otherwise the core uses the
+    /// type of the call target, if it can resolve one. Useful for recovering constant arguments
+    /// such as syscall numbers or format strings:
+    /// ```
+    /// # use binaryninja::function::Function;
+    /// # fn example(function: &Function, call_site: u64) {
+    /// if let Some(format) = function.parameter_value_at(call_site, None, 0).constant() {
+    ///     println!("printf called with format string at {:#x}", format);
+    /// }
+    /// # }
+    /// ```
+    pub fn parameter_value_at(
+        &self,
+        addr: u64,
+        function_type: Option<&Type>,
+        index: usize,
+    ) -> RegisterValue {
+        unsafe {
+            BNGetParameterValueAtInstruction(
+                self.handle,
+                self.arch().0,
+                addr,
+                function_type.map_or(ptr::null_mut(), |t| t.handle),
+                index,
+            )
+            .into()
+        }
+    }
 }
 
 impl fmt::Debug for Function {
@@ -344,3 +384,37 @@ unsafe impl<'a> CoreArrayWrapper<'a> for AddressRange {
         mem::transmute(raw)
     }
 }
+
+/////////////////
+// RegisterValue
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct RegisterValue {
+    pub state: RegisterValueType,
+    pub value: i64,
+    pub offset: i64,
+    pub size: usize,
+}
+
+impl RegisterValue {
+    /// The value, if data flow determined it to be a constant or a constant pointer
+    pub fn constant(&self) -> Option<u64> {
+        match self.state {
+            RegisterValueType::ConstantValue | RegisterValueType::ConstantPointerValue => {
+                Some(self.value as u64)
+            }
+            _ => None,
+        }
+    }
+}
+
+impl From<BNRegisterValue> for RegisterValue {
+    fn from(value: BNRegisterValue) -> Self {
+        Self {
+            state: value.state,
+            value: value.value,
+            offset: value.offset,
+            size: value.size,
+        }
+    }
+}
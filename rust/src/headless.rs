@@ -80,6 +80,46 @@ pub fn init() {
     }
 }
 
+/// Whether the core has a validated license, which headless use requires
+///
+/// Which editions support headless operation is decided by the core itself, so this doesn't
+/// second-guess the [product type](crate::product_type). Only meaningful after [`init`] has been
+/// called, as that is when the license is loaded.
+pub fn is_headless_permitted() -> bool {
+    crate::is_license_validated()
+}
+
+/// Checks that the core is licensed for headless use, with an actionable message if it isn't
+///
+/// A license whose [update period](crate::license_expiration_time) has ended still works, so that
+/// is only logged as a warning.
+///
+/// ```rust
+/// binaryninja::headless::init();
+/// if let Err(msg) = binaryninja::headless::check_license() {
+///     eprintln!("{}", msg);
+///     binaryninja::headless::shutdown();
+///     std::process::exit(1);
+/// }
+/// ```
+pub fn check_license() -> Result<(), String> {
+    if !crate::is_license_validated() {
+        return Err(
+            "License is not valid. Please supply a valid license (see `binaryninja::set_license`)."
+                .to_string(),
+        );
+    }
+
+    if crate::license_expiration_time() < std::time::SystemTime::now() {
+        log::warn!(
+            "The update period of the license for {} has ended; newer versions will not be available.",
+            crate::licensed_user_email()
+        );
+    }
+
+    Ok(())
+}
+
 /// Unloads plugins, stops all worker threads, and closes open logs
 ///
 /// ⚠️ Important! Must be called at the end of scripts. ⚠️
@@ -114,6 +114,8 @@ pub extern crate binaryninjacore_sys;
 extern crate libc;
 #[cfg(feature = "rayon")]
 extern crate rayon;
+#[cfg(feature = "serde_json")]
+extern crate serde_json;
 
 // TODO
 // move some options to results
@@ -153,14 +155,20 @@ pub mod linearview;
 pub mod llil;
 pub mod logger;
 pub mod metadata;
+pub mod panic;
 pub mod platform;
 pub mod rc;
+#[cfg(feature = "serde_json")]
+pub mod report;
 pub mod section;
 pub mod segment;
 pub mod settings;
 pub mod string;
 pub mod symbol;
+pub mod symbolstore;
 pub mod tags;
+pub mod transform;
+pub mod typeprinter;
 pub mod types;
 
 use std::collections::HashMap;
@@ -262,6 +270,30 @@ pub fn open_view<F: AsRef<Path>>(filename: F) -> Result<rc::Ref<binaryview::Bina
     Ok(bv)
 }
 
+/// Opens an existing database without picking a view, so that any of the view types stored in it can be chosen:
+/// ```rust
+/// let file = binaryninja::open_database("/tmp/cat.bndb").expect("Couldn't open `/tmp/cat.bndb`");
+/// for view_type in &file.existing_views() {
+///     println!("{}", view_type);
+/// }
+///
+/// let bv = file.get_view_of_type("ELF").expect("Database has no ELF view");
+/// ```
+pub fn open_database<F: AsRef<Path>>(
+    filename: F,
+) -> Result<rc::Ref<filemetadata::FileMetadata>, String> {
+    let filename = filename.as_ref();
+    let mut metadata = filemetadata::FileMetadata::with_filename(
+        filename
+            .to_str()
+            .ok_or_else(|| "Path is not valid UTF-8".to_string())?,
+    );
+
+    // The file keeps every view it opens alive, so the default view returned here can be dropped
+    open_binary_file(&mut metadata, true, false)?;
+    Ok(metadata)
+}
+
 /// This is incomplete, but should work in most cases:
 /// ```rust
 /// let settings = [("analysis.linearSweep.autorun", "false")]
@@ -504,6 +536,58 @@ pub fn version() -> string::BnString {
     unsafe { string::BnString::from_raw(binaryninjacore_sys::BNGetVersionString()) }
 }
 
+pub fn serial_number() -> string::BnString {
+    unsafe { string::BnString::from_raw(binaryninjacore_sys::BNGetSerialNumber()) }
+}
+
+pub fn is_license_validated() -> bool {
+    unsafe { binaryninjacore_sys::BNIsLicenseValidated() }
+}
+
+/// End of the current license's update period
+///
+/// Perpetual licenses keep working after this time; only updates stop.
+pub fn license_expiration_time() -> std::time::SystemTime {
+    let seconds = unsafe { binaryninjacore_sys::BNGetLicenseExpirationTime() };
+    std::time::UNIX_EPOCH + std::time::Duration::from_secs(seconds)
+}
+
+pub fn licensed_user_email() -> string::BnString {
+    unsafe { string::BnString::from_raw(binaryninjacore_sys::BNGetLicensedUserEmail()) }
+}
+
+/// Product string from the license file (e.g. "Binary Ninja Enterprise Client")
+pub fn product() -> string::BnString {
+    unsafe { string::BnString::from_raw(binaryninjacore_sys::BNGetProduct()) }
+}
+
+/// Product type from the license file
+pub fn product_type() -> string::BnString {
+    unsafe { string::BnString::from_raw(binaryninjacore_sys::BNGetProductType()) }
+}
+
+pub fn license_count() -> i32 {
+    unsafe { binaryninjacore_sys::BNGetLicenseCount() }
+}
+
+/// Indicates that a UI exists and the UI has invoked BNInitUI
+pub fn is_ui_enabled() -> bool {
+    unsafe { binaryninjacore_sys::BNIsUIEnabled() }
+}
+
+/// Initialize the core with the contents of a license file, rather than the license file on disk
+///
+/// Useful for headless environments (such as containers), where the license can be provided through an environment variable.
+/// Must be called before [`headless::init`].
+pub fn set_license<S: string::BnStrCompatible>(license_data: S) {
+    let license_data = license_data.into_bytes_with_nul();
+    unsafe {
+        binaryninjacore_sys::BNSetLicense(
+            license_data.as_ref().as_ptr() as *const std::os::raw::c_char
+        )
+    };
+}
+
 pub fn plugin_abi_version() -> u32 {
     binaryninjacore_sys::BN_CURRENT_CORE_ABI_VERSION
 }
@@ -97,6 +97,7 @@ where
     match op.operation {
         LLIL_CONST => ExprInfo::Const(Operation::new(function, op)),
         LLIL_CONST_PTR => ExprInfo::ConstPtr(Operation::new(function, op)),
+        LLIL_EXTERN_PTR => ExprInfo::ExternPtr(Operation::new(function, op)),
 
         LLIL_ADD => ExprInfo::Add(Operation::new(function, op)),
         LLIL_ADC => ExprInfo::Adc(Operation::new(function, op)),
@@ -355,6 +356,7 @@ where
     Reg(Operation<'func, A, M, F, operation::Reg>),
     Const(Operation<'func, A, M, F, operation::Const>),
     ConstPtr(Operation<'func, A, M, F, operation::Const>),
+    ExternPtr(Operation<'func, A, M, F, operation::Extern>),
     Flag(Operation<'func, A, M, F, operation::Flag>),
     FlagBit(Operation<'func, A, M, F, operation::FlagBit>),
 
@@ -543,6 +545,8 @@ where
 
             Const(ref op) | ConstPtr(ref op) => &op.op,
 
+            ExternPtr(ref op) => &op.op,
+
             Adc(ref op) | Sbb(ref op) | Rlc(ref op) | Rrc(ref op) => &op.op,
 
             Add(ref op) | Sub(ref op) | And(ref op) | Or(ref op) | Xor(ref op) | Lsl(ref op)
@@ -592,6 +596,8 @@ where
 
             Const(ref op) | ConstPtr(ref op) => op.flag_write(),
 
+            ExternPtr(ref op) => op.flag_write(),
+
             Adc(ref op) | Sbb(ref op) | Rlc(ref op) | Rrc(ref op) => op.flag_write(),
 
             Add(ref op) | Sub(ref op) | And(ref op) | Or(ref op) | Xor(ref op) | Lsl(ref op)
@@ -674,6 +680,11 @@ where
 
             Const(ref op) | ConstPtr(ref op) => write!(f, "0x{:x}", op.value()),
 
+            ExternPtr(ref op) => match op.offset() {
+                0 => write!(f, "0x{:x}", op.value()),
+                offset => write!(f, "0x{:x} + 0x{:x}", op.value(), offset),
+            },
+
             Adc(ref op) | Sbb(ref op) | Rlc(ref op) | Rrc(ref op) => {
                 let left = op.left();
                 let right = op.right();
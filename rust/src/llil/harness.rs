@@ -0,0 +1,120 @@
+// Copyright 2021-2023 Vector 35 Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Lifting raw bytes without a `BinaryView`, for regression tests of architecture plugins.
+//!
+//! The architecture must still be registered with the core (so the core has to be initialized),
+//! but no file, view, or analysis is involved:
+//! ```
+//! # use binaryninja::architecture::CoreArchitecture;
+//! binaryninja::headless::init();
+//! // Or the architecture registered by the plugin under test
+//! let arch = CoreArchitecture::by_name("aarch64").expect("aarch64 is not registered");
+//!
+//! let lifted = binaryninja::llil::lift_bytes(&arch, &[0x40, 0x00, 0x80, 0x52], 0x1000);
+//! assert_eq!(lifted.length, 4);
+//! assert_eq!(lifted.instructions, vec!["w0 = 2"]);
+//!
+//! binaryninja::headless::shutdown();
+//! ```
+
+use binaryninjacore_sys::{
+    BNCreateLowLevelILFunction, BNFinalizeLowLevelILFunction, BNFreeInstructionText,
+    BNGetLowLevelILExprText, BNGetLowLevelILIndexForInstruction, BNGetLowLevelILInstructionCount,
+    BNInstructionTextToken,
+};
+
+use std::ptr;
+use std::slice;
+
+use crate::rc::Ref;
+use crate::string::BnStr;
+
+use super::*;
+
+/// The IL produced by [`lift_bytes`], rendered as text so it can be compared with `assert_eq!`
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct LiftedBytes {
+    /// One entry per IL instruction, in the order they were emitted
+    pub instructions: Vec<String>,
+    /// Number of bytes consumed before lifting stopped
+    pub length: usize,
+    /// Whether every instruction in `data` lifted successfully
+    pub complete: bool,
+}
+
+/// Lifts the instructions in `data`, as if loaded at `addr`, until the data runs out or an
+/// instruction fails to lift
+pub fn lift_bytes<A: Architecture>(arch: &A, data: &[u8], addr: u64) -> LiftedBytes {
+    let core_arch = *arch.as_ref();
+
+    let mut lifter = unsafe {
+        let il = BNCreateLowLevelILFunction(core_arch.0, ptr::null_mut());
+        Ref::new(Lifter::from_raw(arch.handle(), il))
+    };
+
+    let mut length = 0;
+    let mut complete = true;
+    while length < data.len() {
+        let current = addr.wrapping_add(length as u64);
+        lifter.set_current_address(current);
+
+        // An instruction reported as longer than the remaining data can't have been lifted from it
+        match arch.instruction_llil(&data[length..], current, &mut lifter) {
+            Some((instr_len, true)) if instr_len > 0 && instr_len <= data.len() - length => {
+                length += instr_len
+            }
+            _ => {
+                complete = false;
+                break;
+            }
+        }
+    }
+
+    unsafe { BNFinalizeLowLevelILFunction(lifter.handle) };
+
+    let count = unsafe { BNGetLowLevelILInstructionCount(lifter.handle) };
+    let instructions = (0..count)
+        .map(|i| unsafe {
+            let expr_idx = BNGetLowLevelILIndexForInstruction(lifter.handle, i);
+
+            let mut tokens: *mut BNInstructionTextToken = ptr::null_mut();
+            let mut token_count = 0;
+            if !BNGetLowLevelILExprText(
+                lifter.handle,
+                core_arch.0,
+                expr_idx,
+                ptr::null_mut(),
+                &mut tokens,
+                &mut token_count,
+            ) {
+                return String::new();
+            }
+
+            let text = slice::from_raw_parts(tokens, token_count)
+                .iter()
+                .map(|token| BnStr::from_raw(token.text).to_string())
+                .collect();
+
+            BNFreeInstructionText(tokens, token_count);
+            text
+        })
+        .collect();
+
+    LiftedBytes {
+        instructions,
+        length,
+        complete,
+    }
+}
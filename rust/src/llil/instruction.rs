@@ -15,12 +15,20 @@
 use binaryninjacore_sys::BNGetLowLevelILByIndex;
 use binaryninjacore_sys::BNGetLowLevelILIndexForInstruction;
 use binaryninjacore_sys::BNLowLevelILInstruction;
+use binaryninjacore_sys::{
+    BNFreeILInstructionList, BNFreeRegisterList, BNGetFlagsReadByLiftedILInstruction,
+    BNGetFlagsWrittenByLiftedILInstruction, BNGetLiftedILFlagDefinitionsForUse,
+    BNGetLiftedILFlagUsesForDefinition, BNGetLowLevelILOwnerFunction,
+};
+
+use std::slice;
 
 use super::operation;
 use super::operation::Operation;
 use super::*;
 
-use crate::architecture::Architecture;
+use crate::architecture::{Architecture, Flag};
+use crate::rc::Ref;
 
 pub struct Instruction<'func, A, M, F>
 where
@@ -156,6 +164,112 @@ where
     }
 }
 
+impl<'func, A> Instruction<'func, A, Finalized, NonSSA<LiftedNonSSA>>
+where
+    A: 'func + Architecture,
+{
+    fn owner_function(&self) -> Option<Ref<crate::function::Function>> {
+        let func = unsafe { BNGetLowLevelILOwnerFunction(self.function.handle) };
+        if func.is_null() {
+            return None;
+        }
+
+        Some(unsafe { crate::function::Function::from_raw(func) })
+    }
+
+    // Temporary flags have no corresponding architecture flag, and are skipped
+    unsafe fn flags_from_raw(&self, flags: *mut u32, count: usize) -> Vec<A::Flag> {
+        let result = slice::from_raw_parts(flags, count)
+            .iter()
+            .filter_map(|&id| self.function.arch().flag_from_id(id))
+            .collect();
+
+        BNFreeRegisterList(flags);
+        result
+    }
+
+    unsafe fn instructions_from_raw(&self, instrs: *mut usize, count: usize) -> Vec<Self> {
+        let result = slice::from_raw_parts(instrs, count)
+            .iter()
+            .map(|&instr_idx| Instruction {
+                function: self.function,
+                instr_idx,
+            })
+            .collect();
+
+        BNFreeILInstructionList(instrs);
+        result
+    }
+
+    /// Flags read by this instruction
+    pub fn flags_read(&self) -> Vec<A::Flag> {
+        let func = match self.owner_function() {
+            Some(func) => func,
+            None => return Vec::new(),
+        };
+
+        unsafe {
+            let mut count = 0;
+            let flags =
+                BNGetFlagsReadByLiftedILInstruction(func.handle, self.instr_idx, &mut count);
+            self.flags_from_raw(flags, count)
+        }
+    }
+
+    /// Flags written by this instruction
+    pub fn flags_written(&self) -> Vec<A::Flag> {
+        let func = match self.owner_function() {
+            Some(func) => func,
+            None => return Vec::new(),
+        };
+
+        unsafe {
+            let mut count = 0;
+            let flags =
+                BNGetFlagsWrittenByLiftedILInstruction(func.handle, self.instr_idx, &mut count);
+            self.flags_from_raw(flags, count)
+        }
+    }
+
+    /// Instructions that read the value of `flag` written by this instruction
+    pub fn flag_uses(&self, flag: A::Flag) -> Vec<Self> {
+        let func = match self.owner_function() {
+            Some(func) => func,
+            None => return Vec::new(),
+        };
+
+        unsafe {
+            let mut count = 0;
+            let instrs = BNGetLiftedILFlagUsesForDefinition(
+                func.handle,
+                self.instr_idx,
+                flag.id(),
+                &mut count,
+            );
+            self.instructions_from_raw(instrs, count)
+        }
+    }
+
+    /// Instructions whose write of `flag` may be the value read by this instruction
+    pub fn flag_definitions(&self, flag: A::Flag) -> Vec<Self> {
+        let func = match self.owner_function() {
+            Some(func) => func,
+            None => return Vec::new(),
+        };
+
+        unsafe {
+            let mut count = 0;
+            let instrs = BNGetLiftedILFlagDefinitionsForUse(
+                func.handle,
+                self.instr_idx,
+                flag.id(),
+                &mut count,
+            );
+            self.instructions_from_raw(instrs, count)
+        }
+    }
+}
+
 pub enum InstrInfo<'func, A, M, F>
 where
     A: 'func + Architecture,
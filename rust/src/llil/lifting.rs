@@ -18,6 +18,7 @@ use std::mem;
 use crate::architecture::Architecture;
 use crate::architecture::Register as ArchReg;
 use crate::architecture::{Flag, FlagClass, FlagCondition, FlagGroup, FlagRole, FlagWrite};
+use crate::symbol::{Symbol, SymbolType};
 
 use super::*;
 
@@ -933,6 +934,44 @@ where
         self.const_ptr_sized(self.arch().address_size(), val)
     }
 
+    /// A pointer to `val + offset` that lives outside the binary, such as one produced by a
+    /// relocation against an external symbol
+    pub fn extern_ptr_sized(
+        &self,
+        size: usize,
+        val: u64,
+        offset: u64,
+    ) -> Expression<A, Mutable, NonSSA<LiftedNonSSA>, ValueExpr> {
+        use binaryninjacore_sys::BNLowLevelILAddExpr;
+        use binaryninjacore_sys::BNLowLevelILOperation::LLIL_EXTERN_PTR;
+
+        let expr_idx = unsafe {
+            BNLowLevelILAddExpr(self.handle, LLIL_EXTERN_PTR, size, 0, val, offset, 0, 0)
+        };
+
+        Expression::new(self, expr_idx)
+    }
+
+    pub fn extern_ptr(
+        &self,
+        val: u64,
+        offset: u64,
+    ) -> Expression<A, Mutable, NonSSA<LiftedNonSSA>, ValueExpr> {
+        self.extern_ptr_sized(self.arch().address_size(), val, offset)
+    }
+
+    /// A pointer to `symbol`: an extern pointer for external symbols and a constant pointer
+    /// otherwise, so that either way a reference to the symbol is recorded
+    pub fn symbol_ptr(
+        &self,
+        symbol: &Symbol,
+    ) -> Expression<A, Mutable, NonSSA<LiftedNonSSA>, ValueExpr> {
+        match symbol.sym_type() {
+            SymbolType::External => self.extern_ptr(symbol.address(), 0),
+            _ => self.const_ptr(symbol.address()),
+        }
+    }
+
     pub fn trap(&self, val: u64) -> Expression<A, Mutable, NonSSA<LiftedNonSSA>, VoidExpr> {
         use binaryninjacore_sys::BNLowLevelILAddExpr;
         use binaryninjacore_sys::BNLowLevelILOperation::LLIL_TRAP;
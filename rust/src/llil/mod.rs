@@ -27,12 +27,14 @@ use crate::function::Location;
 mod block;
 mod expression;
 mod function;
+mod harness;
 mod instruction;
 mod lifting;
 pub mod operation;
 
 pub use self::expression::*;
 pub use self::function::*;
+pub use self::harness::{lift_bytes, LiftedBytes};
 pub use self::instruction::*;
 pub use self::lifting::get_default_flag_cond_llil;
 pub use self::lifting::get_default_flag_write_llil;
@@ -473,6 +473,28 @@ where
     }
 }
 
+// LLIL_EXTERN_PTR
+pub struct Extern;
+
+impl<'func, A, M, F> Operation<'func, A, M, F, Extern>
+where
+    A: 'func + Architecture,
+    M: FunctionMutability,
+    F: FunctionForm,
+{
+    pub fn size(&self) -> usize {
+        self.op.size
+    }
+
+    pub fn value(&self) -> u64 {
+        self.op.operands[0]
+    }
+
+    pub fn offset(&self) -> u64 {
+        self.op.operands[1]
+    }
+}
+
 // LLIL_ADD, LLIL_SUB, LLIL_AND, LLIL_OR
 // LLIL_XOR, LLIL_LSL, LLIL_LSR, LLIL_ASR
 // LLIL_ROL, LLIL_ROR, LLIL_MUL, LLIL_MULU_DP,
@@ -638,6 +660,7 @@ impl OperationArguments for RegPhi {}
 impl OperationArguments for FlagPhi {}
 impl OperationArguments for MemPhi {}
 impl OperationArguments for Const {}
+impl OperationArguments for Extern {}
 impl OperationArguments for BinaryOp {}
 impl OperationArguments for BinaryOpCarry {}
 impl OperationArguments for DoublePrecDivOp {}
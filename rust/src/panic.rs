@@ -0,0 +1,69 @@
+// Copyright 2021-2023 Vector 35 Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Opt-in recovery from panics raised inside callbacks invoked by the core.
+//!
+//! By default, a panic that unwinds to the FFI boundary of a registered callback aborts the whole
+//! process (taking Binary Ninja down with it). Core plugins can instead call [`install_hook`] from
+//! their `CorePluginInit`:
+//!
+//! ```
+//! #[no_mangle]
+//! pub extern "C" fn CorePluginInit() -> bool {
+//!     binaryninja::logger::init(log::LevelFilter::Info).expect("Unable to initialize logger");
+//!     binaryninja::panic::install_hook();
+//!     // Register your architectures, debug info parsers, etc...
+//!     true
+//! }
+//! ```
+//!
+//! Once installed, panics are logged (with a backtrace) through the core logger, and the following
+//! callbacks report failure to the core instead of aborting:
+//!  * [`Architecture::instruction_info`](crate::architecture::Architecture::instruction_info),
+//!    [`instruction_text`](crate::architecture::Architecture::instruction_text), and
+//!    [`instruction_llil`](crate::architecture::Architecture::instruction_llil)
+//!  * [`CustomDebugInfoParser::is_valid`](crate::debuginfo::CustomDebugInfoParser::is_valid) and
+//!    [`parse_info`](crate::debuginfo::CustomDebugInfoParser::parse_info)
+//!
+//! A panic in any other callback, including every other `Architecture` method, still aborts.
+//!
+//! A panic partway through `instruction_llil` fails the instruction, but any IL the lifter already
+//! emitted for it is not removed from the function.
+
+use std::backtrace::Backtrace;
+use std::panic;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+static RECOVER_FROM_PANICS: AtomicBool = AtomicBool::new(false);
+
+/// Installs a panic hook that reports panics through the core logger and enables recovery from panics in callbacks
+///
+/// The previously-installed hook (usually the default hook, which prints to stderr) is replaced.
+/// For the log messages to reach Binary Ninja, [`crate::logger::init`] must also have been called.
+pub fn install_hook() {
+    panic::set_hook(Box::new(|info| {
+        let backtrace = Backtrace::force_capture();
+        let thread = std::thread::current();
+        let thread_name = thread.name().unwrap_or("<unnamed>");
+
+        error!("thread '{}' {}\n{}", thread_name, info, backtrace);
+    }));
+
+    RECOVER_FROM_PANICS.store(true, Ordering::SeqCst);
+}
+
+/// Whether [`install_hook`] has been called; callbacks with a failure value consult this before aborting
+pub fn recovery_enabled() -> bool {
+    RECOVER_FROM_PANICS.load(Ordering::SeqCst)
+}
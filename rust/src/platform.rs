@@ -287,16 +287,20 @@ impl TypeParser for Platform {
         let filename = filename.into_bytes_with_nul();
         let auto_type_source = auto_type_source.into_bytes_with_nul();
 
-        let mut include_dirs = vec![];
-
-        for dir in include_directories.iter() {
-            let d = dir
-                .as_ref()
-                .to_string_lossy()
-                .to_string()
-                .into_bytes_with_nul();
-            include_dirs.push(d.as_ptr() as _);
-        }
+        // The owned strings have to outlive the call, not just the loop building the pointer list
+        let include_dir_strings: Vec<_> = include_directories
+            .iter()
+            .map(|dir| {
+                dir.as_ref()
+                    .to_string_lossy()
+                    .to_string()
+                    .into_bytes_with_nul()
+            })
+            .collect();
+        let mut include_dirs: Vec<_> = include_dir_strings
+            .iter()
+            .map(|d| d.as_ptr() as _)
+            .collect();
 
         unsafe {
             let success = BNParseTypesFromSource(
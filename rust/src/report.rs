@@ -0,0 +1,195 @@
+// Copyright 2021-2023 Vector 35 Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Serializes the results of analysis into a JSON report for consumption by other tools.
+//!
+//! Requires the `serde_json` feature. The schema is versioned by the top-level `"version"` field;
+//! fields are only ever added within a version, never renamed or removed.
+//!
+//! ```
+//! binaryninja::headless::init();
+//! let bv = binaryninja::open_view("/bin/cat").expect("Couldn't open `/bin/cat`");
+//! let report = binaryninja::report::analysis_report(&bv);
+//! println!("{}", serde_json::to_string_pretty(&report).unwrap());
+//! binaryninja::headless::shutdown();
+//! ```
+//!
+//! Produces:
+//! ```json
+//! {
+//!   "version": 1,
+//!   "file": "/bin/cat",
+//!   "view_type": "ELF",
+//!   "architecture": "x86_64",
+//!   "platform": "linux-x86_64",
+//!   "entry_point": 18672,
+//!   "functions": [{ "address": 16384, "name": "_init", "prototype": "int64_t _init()", "size": 27 }],
+//!   "strings": [{ "address": 792, "length": 27, "type": "ascii", "value": "/lib64/ld-linux-x86-64.so.2" }],
+//!   "imports": [{ "address": 92896, "name": "free", "type": "ImportAddress" }],
+//!   "exports": [{ "address": 18672, "name": "_start", "type": "Function" }],
+//!   "sections": [{ "name": ".text", "start": 16672, "end": 83698, "type": "PROGBITS", "semantics": "ReadOnlyCode" }]
+//! }
+//! ```
+
+use serde_json::{json, Value};
+
+use crate::{
+    architecture::Architecture,
+    binaryview::{BinaryView, BinaryViewBase, BinaryViewExt, StringType},
+    function::Function,
+    section::Semantics,
+    symbol::{Symbol, SymbolType},
+    typeprinter::{CoreTypePrinter, TokenEscapingType},
+};
+
+/// Version of the schema produced by [`analysis_report`]
+pub const REPORT_VERSION: u64 = 1;
+
+/// Builds the full report for `view`. Analysis should be complete before calling this
+pub fn analysis_report(view: &BinaryView) -> Value {
+    json!({
+        "version": REPORT_VERSION,
+        "file": view.file().filename().to_string(),
+        "view_type": view.type_name().to_string(),
+        "architecture": view.default_arch().map(|arch| arch.name().to_string()),
+        "platform": view.default_platform().map(|platform| platform.name().to_string()),
+        "entry_point": view.entry_point(),
+        "functions": functions_report(view),
+        "strings": strings_report(view),
+        "imports": imports_report(view),
+        "exports": exports_report(view),
+        "sections": sections_report(view),
+    })
+}
+
+fn function_prototype(printer: Option<&CoreTypePrinter>, function: &Function) -> String {
+    let function_type = function.function_type();
+    let name = function.symbol().full_name();
+
+    printer
+        .and_then(|printer| {
+            printer
+                .type_string(
+                    &function_type,
+                    Some(&function.platform()),
+                    name.as_str(),
+                    TokenEscapingType::NoTokenEscapingType,
+                )
+                .ok()
+        })
+        .map_or_else(|| format!("{} {}", function_type, name), |s| s.to_string())
+}
+
+pub fn functions_report(view: &BinaryView) -> Vec<Value> {
+    let printer = CoreTypePrinter::try_default().ok();
+
+    view.functions()
+        .iter()
+        .map(|function| {
+            let size: u64 = function
+                .address_ranges()
+                .iter()
+                .map(|range| range.end() - range.start())
+                .sum();
+
+            json!({
+                "address": function.start(),
+                "name": function.symbol().full_name().to_string(),
+                "prototype": function_prototype(printer.as_ref(), &function),
+                "size": size,
+            })
+        })
+        .collect()
+}
+
+pub fn strings_report(view: &BinaryView) -> Vec<Value> {
+    view.strings()
+        .iter()
+        .map(|string| {
+            let string_type = match string.string_type() {
+                StringType::AsciiString => "ascii",
+                StringType::Utf8String => "utf8",
+                StringType::Utf16String => "utf16",
+                StringType::Utf32String => "utf32",
+            };
+
+            json!({
+                "address": string.start(),
+                "length": string.len(),
+                "type": string_type,
+                "value": view.read_string(string),
+            })
+        })
+        .collect()
+}
+
+// Spelled out rather than derived from `Debug`, so renaming a variant can't change the schema
+fn symbol_type_name(sym_type: SymbolType) -> &'static str {
+    match sym_type {
+        SymbolType::Function => "Function",
+        SymbolType::LibraryFunction => "LibraryFunction",
+        SymbolType::ImportAddress => "ImportAddress",
+        SymbolType::ImportedFunction => "ImportedFunction",
+        SymbolType::Data => "Data",
+        SymbolType::ImportedData => "ImportedData",
+        SymbolType::External => "External",
+    }
+}
+
+fn semantics_name(semantics: Semantics) -> &'static str {
+    match semantics {
+        Semantics::DefaultSection => "DefaultSection",
+        Semantics::ReadOnlyCode => "ReadOnlyCode",
+        Semantics::ReadOnlyData => "ReadOnlyData",
+        Semantics::ReadWriteData => "ReadWriteData",
+        Semantics::External => "External",
+    }
+}
+
+fn symbol_report(symbol: &Symbol) -> Value {
+    json!({
+        "address": symbol.address(),
+        "name": symbol.full_name().to_string(),
+        "type": symbol_type_name(symbol.sym_type()),
+    })
+}
+
+pub fn imports_report(view: &BinaryView) -> Vec<Value> {
+    view.imports()
+        .iter()
+        .map(|symbol| symbol_report(symbol))
+        .collect()
+}
+
+pub fn exports_report(view: &BinaryView) -> Vec<Value> {
+    view.exports()
+        .iter()
+        .map(|symbol| symbol_report(symbol))
+        .collect()
+}
+
+pub fn sections_report(view: &BinaryView) -> Vec<Value> {
+    view.sections()
+        .iter()
+        .map(|section| {
+            json!({
+                "name": section.name().to_string(),
+                "start": section.start(),
+                "end": section.end(),
+                "type": section.section_type().to_string(),
+                "semantics": semantics_name(section.semantics()),
+            })
+        })
+        .collect()
+}
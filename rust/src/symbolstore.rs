@@ -0,0 +1,287 @@
+// Copyright 2021-2023 Vector 35 Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Fetching detached debug information from symbol servers.
+//!
+//! Symbol files are looked up by the identifier embedded in the binary (a GNU build-id for ELF, or
+//! the PDB GUID and age for PE) and fetched over HTTP through the core's download provider, so
+//! proxy and certificate settings are honored. Downloads are cached on disk and the cache is
+//! always checked first.
+//!
+//! Two server layouts are supported, and a server may be a mirror of either:
+//!  * [debuginfod](https://sourceware.org/elfutils/Debuginfod.html): `<server>/buildid/<build-id>/debuginfo`
+//!  * Microsoft symstore (also used by Artifactory): `<server>/<pdb name>/<GUID><age>/<pdb name>`
+//!
+//! Debug info parsers would typically do something like:
+//! ```
+//! use binaryninja::symbolstore::{SymbolKey, SymbolStore};
+//!
+//! # binaryninja::headless::init();
+//! # let bv = binaryninja::open_view("/bin/cat").expect("Couldn't open `/bin/cat`");
+//! let store = SymbolStore::from_settings().expect("Couldn't read symbol store settings");
+//! if let Some(key) = SymbolKey::from_build_id_note(&bv) {
+//!     if let Ok(path) = store.fetch(&key) {
+//!         let data = std::fs::read(path).unwrap();
+//!     }
+//! }
+//! # binaryninja::headless::shutdown();
+//! ```
+
+use std::cell::RefCell;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::rc::Rc;
+
+use crate::binaryview::{BinaryView, BinaryViewBase, BinaryViewExt};
+use crate::downloadprovider::{DownloadInstanceInputOutputCallbacks, DownloadProvider};
+use crate::settings::Settings;
+use crate::Endianness;
+
+const NT_GNU_BUILD_ID: u32 = 3;
+
+/// Identifies a symbol file independently of where it is stored
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum SymbolKey {
+    /// A GNU build-id, as a lowercase hex string
+    BuildId(String),
+    /// A PDB, by file name (without directories), GUID (in any common format), and age
+    Pdb {
+        name: String,
+        guid: String,
+        age: u32,
+    },
+}
+
+impl SymbolKey {
+    /// The GNU build-id of an ELF view, read from its `.note.gnu.build-id` section
+    pub fn from_build_id_note(view: &BinaryView) -> Option<Self> {
+        let section = view.section_by_name(".note.gnu.build-id").ok()?;
+        let note = view.read_vec(section.start(), section.len());
+
+        let read_u32 = |offset: usize| -> Option<u32> {
+            let bytes: [u8; 4] = note.get(offset..offset + 4)?.try_into().ok()?;
+            Some(match view.default_endianness() {
+                Endianness::BigEndian => u32::from_be_bytes(bytes),
+                Endianness::LittleEndian => u32::from_le_bytes(bytes),
+            })
+        };
+
+        // Elf_Nhdr followed by the 4-byte aligned owner name ("GNU\0") and the descriptor. The sizes
+        // come from the file, so the offsets computed from them are checked
+        let name_size = read_u32(0)? as usize;
+        let desc_size = read_u32(4)? as usize;
+        if read_u32(8)? != NT_GNU_BUILD_ID
+            || note.get(12..12usize.checked_add(name_size)?)? != b"GNU\0"
+        {
+            return None;
+        }
+
+        let desc_start = 12usize.checked_add(name_size.checked_add(3)? & !3)?;
+        let build_id = note.get(desc_start..desc_start.checked_add(desc_size)?)?;
+        if build_id.is_empty() {
+            return None;
+        }
+
+        Some(SymbolKey::BuildId(
+            build_id.iter().map(|b| format!("{:02x}", b)).collect(),
+        ))
+    }
+
+    /// Path of the symbol file relative to the root of a server or of the cache
+    ///
+    /// Keys usually come from the binary being analyzed, so they are checked before use: build-ids
+    /// must be hex, and only the final component of a PDB name is kept (CodeView records often
+    /// hold a full path such as `C:\build\foo.pdb`). Fails if what remains can't be used safely.
+    pub fn relative_path(&self) -> Result<String, String> {
+        match self {
+            SymbolKey::BuildId(build_id) => {
+                if build_id.is_empty() || !build_id.chars().all(|c| c.is_ascii_hexdigit()) {
+                    return Err(format!("Invalid build-id `{}`", build_id));
+                }
+                Ok(format!("buildid/{}/debuginfo", build_id.to_lowercase()))
+            }
+            SymbolKey::Pdb { name, guid, age } => {
+                let file_name = name.rsplit(['/', '\\']).next().unwrap_or_default();
+                if file_name.is_empty()
+                    || file_name == "."
+                    || file_name == ".."
+                    || file_name.contains([':', '\0'])
+                {
+                    return Err(format!("Invalid PDB name `{}`", name));
+                }
+
+                let guid_hex: String = guid
+                    .chars()
+                    .filter(char::is_ascii_hexdigit)
+                    .map(|c| c.to_ascii_uppercase())
+                    .collect();
+                if guid_hex.is_empty() {
+                    return Err(format!("Invalid PDB GUID `{}`", guid));
+                }
+                Ok(format!("{}/{}{:X}/{}", file_name, guid_hex, age, file_name))
+            }
+        }
+    }
+}
+
+pub struct SymbolStore {
+    servers: Vec<String>,
+    cache_directory: PathBuf,
+}
+
+impl SymbolStore {
+    pub fn new<P: Into<PathBuf>>(servers: Vec<String>, cache_directory: P) -> Self {
+        Self {
+            servers,
+            cache_directory: cache_directory.into(),
+        }
+    }
+
+    /// Registers the `symbolstore.*` settings; call once from `CorePluginInit`
+    pub fn register_settings() {
+        let settings = Settings::new("");
+        settings.register_group("symbolstore", "Symbol Store");
+        settings.register_setting_json(
+            "symbolstore.servers",
+            r#"{
+                "title" : "Symbol Servers",
+                "type" : "array",
+                "elementType" : "string",
+                "default" : [],
+                "description" : "Base URLs of debuginfod or symstore-compatible servers, queried in order.",
+                "ignore" : ["SettingsProjectScope", "SettingsResourceScope"]
+            }"#,
+        );
+        settings.register_setting_json(
+            "symbolstore.cachePath",
+            r#"{
+                "title" : "Symbol Cache Path",
+                "type" : "string",
+                "default" : "",
+                "description" : "Directory where downloaded symbol files are kept. Defaults to the 'symbols' folder of the user directory.",
+                "ignore" : ["SettingsProjectScope", "SettingsResourceScope"]
+            }"#,
+        );
+    }
+
+    /// Builds a store from the `symbolstore.*` settings registered by [`SymbolStore::register_settings`]
+    pub fn from_settings() -> Result<Self, String> {
+        let settings = Settings::new("");
+
+        let servers = settings
+            .get_string_list("symbolstore.servers", None, None)
+            .iter()
+            .map(|s| s.to_string())
+            .collect();
+
+        let cache_path = settings.get_string("symbolstore.cachePath", None, None);
+        let cache_directory = if cache_path.is_empty() {
+            crate::user_directory()
+                .map_err(|_| "Could not determine the user directory".to_string())?
+                .join("symbols")
+        } else {
+            PathBuf::from(cache_path.to_string())
+        };
+
+        Ok(Self::new(servers, cache_directory))
+    }
+
+    pub fn servers(&self) -> &[String] {
+        &self.servers
+    }
+
+    pub fn cache_directory(&self) -> &Path {
+        &self.cache_directory
+    }
+
+    /// Where `key` is (or would be) cached
+    pub fn cache_path(&self, key: &SymbolKey) -> Result<PathBuf, String> {
+        Ok(self.cache_directory.join(key.relative_path()?))
+    }
+
+    /// Returns the path of a local copy of the symbol file for `key`, downloading it into the cache
+    /// from the first server that has it if it isn't already cached
+    pub fn fetch(&self, key: &SymbolKey) -> Result<PathBuf, String> {
+        let relative_path = key.relative_path()?;
+        let cache_path = self.cache_directory.join(&relative_path);
+        if cache_path.is_file() {
+            return Ok(cache_path);
+        }
+
+        let mut errors = vec![];
+        for server in &self.servers {
+            let url = format!("{}/{}", server.trim_end_matches('/'), relative_path);
+            match download(&url) {
+                Ok(data) => {
+                    store(&cache_path, &data)
+                        .map_err(|e| format!("Failed to cache `{}`: {}", url, e))?;
+                    return Ok(cache_path);
+                }
+                Err(e) => errors.push(format!("{}: {}", url, e)),
+            }
+        }
+
+        if errors.is_empty() {
+            Err(format!(
+                "`{}` is not cached and no symbol servers are configured",
+                relative_path
+            ))
+        } else {
+            Err(errors.join("\n"))
+        }
+    }
+}
+
+fn download(url: &str) -> Result<Vec<u8>, String> {
+    let provider = DownloadProvider::try_default()
+        .map_err(|_| "No download provider is available".to_string())?;
+    let mut instance = provider
+        .create_instance()
+        .map_err(|_| "Could not create a download instance".to_string())?;
+
+    let data = Rc::new(RefCell::new(Vec::new()));
+    let sink = data.clone();
+    let response = instance
+        .perform_custom_request(
+            "GET",
+            url,
+            Vec::<(String, String)>::new(),
+            DownloadInstanceInputOutputCallbacks {
+                read: None,
+                write: Some(Box::new(move |chunk: &[u8]| {
+                    sink.borrow_mut().extend_from_slice(chunk);
+                    chunk.len()
+                })),
+                progress: None,
+            },
+        )
+        .map_err(|e| e.to_string())?;
+
+    if response.status_code != 200 {
+        return Err(format!("HTTP {}", response.status_code));
+    }
+
+    let data = data.borrow().clone();
+    Ok(data)
+}
+
+// Written under a temporary name first so that an interrupted download never looks like a cache hit
+fn store(path: &Path, data: &[u8]) -> std::io::Result<()> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    let partial = path.with_extension("partial");
+    fs::write(&partial, data)?;
+    fs::rename(&partial, path)
+}
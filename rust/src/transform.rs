@@ -0,0 +1,126 @@
+// Copyright 2021-2023 Vector 35 Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Encoders, decoders, and hashes provided by the core, such as `Zlib`, `Base64`, or `XOR`
+//!
+//! ```
+//! use binaryninja::transform::Transform;
+//!
+//! let base64 = Transform::by_name("Base64").expect("Base64 transform is not available");
+//! assert_eq!(base64.encode(b"hello").unwrap(), b"aGVsbG8=");
+//! assert_eq!(base64.decode(b"aGVsbG8=").unwrap(), b"hello");
+//! ```
+
+use binaryninjacore_sys::*;
+
+pub use binaryninjacore_sys::BNTransformType as TransformType;
+
+use std::ptr;
+
+use crate::databuffer::DataBuffer;
+use crate::rc::*;
+use crate::string::*;
+
+#[derive(Copy, Clone, PartialEq, Eq, Hash)]
+pub struct Transform(pub(crate) *mut BNTransform);
+
+impl Transform {
+    pub fn list_all() -> Array<Transform> {
+        unsafe {
+            let mut count: usize = 0;
+            let transforms = BNGetTransformTypeList(&mut count);
+
+            Array::new(transforms, count, ())
+        }
+    }
+
+    /// Looks up a transform by its short name
+    pub fn by_name<N: BnStrCompatible>(name: N) -> Result<Self, ()> {
+        let name = name.into_bytes_with_nul();
+
+        let res = unsafe { BNGetTransformByName(name.as_ref().as_ptr() as *const _) };
+
+        match res.is_null() {
+            false => Ok(Transform(res)),
+            true => Err(()),
+        }
+    }
+
+    pub fn name(&self) -> BnString {
+        unsafe { BnString::from_raw(BNGetTransformName(self.0)) }
+    }
+
+    pub fn long_name(&self) -> BnString {
+        unsafe { BnString::from_raw(BNGetTransformLongName(self.0)) }
+    }
+
+    pub fn group(&self) -> BnString {
+        unsafe { BnString::from_raw(BNGetTransformGroup(self.0)) }
+    }
+
+    pub fn transform_type(&self) -> TransformType {
+        unsafe { BNGetTransformType(self.0) }
+    }
+
+    pub fn decode(&self, input: &[u8]) -> Result<Vec<u8>, ()> {
+        self.apply(input, BNDecode)
+    }
+
+    pub fn encode(&self, input: &[u8]) -> Result<Vec<u8>, ()> {
+        self.apply(input, BNEncode)
+    }
+
+    fn apply(
+        &self,
+        input: &[u8],
+        op: unsafe extern "C" fn(
+            *mut BNTransform,
+            *mut BNDataBuffer,
+            *mut BNDataBuffer,
+            *mut BNTransformParameter,
+            usize,
+        ) -> bool,
+    ) -> Result<Vec<u8>, ()> {
+        let input = DataBuffer::new(input)?;
+        let output = DataBuffer::new(&[])?;
+
+        if unsafe { op(self.0, input.0, output.0, ptr::null_mut(), 0) } {
+            Ok(output.get_data().to_vec())
+        } else {
+            Err(())
+        }
+    }
+}
+
+impl CoreArrayProvider for Transform {
+    type Raw = *mut BNTransform;
+    type Context = ();
+}
+
+unsafe impl CoreOwnedArrayProvider for Transform {
+    unsafe fn free(raw: *mut Self::Raw, _count: usize, _context: &Self::Context) {
+        BNFreeTransformTypeList(raw);
+    }
+}
+
+unsafe impl<'a> CoreArrayWrapper<'a> for Transform {
+    type Wrapped = Transform;
+
+    unsafe fn wrap_raw(raw: &'a Self::Raw, _context: &'a Self::Context) -> Self::Wrapped {
+        Transform(*raw)
+    }
+}
+
+unsafe impl Send for Transform {}
+unsafe impl Sync for Transform {}
@@ -0,0 +1,179 @@
+// Copyright 2021-2023 Vector 35 Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Printing types back to source text using the type printers registered with the core.
+//!
+//! To export every type in a view as a C header that can be fed back into a compiler:
+//! ```
+//! use binaryninja::typeprinter::export_c_header;
+//!
+//! let bv = binaryninja::open_view("/bin/cat").expect("Couldn't open `/bin/cat`");
+//! let header = export_c_header(&bv).expect("Couldn't print types");
+//! std::fs::write("cat_types.h", header.as_str()).unwrap();
+//! ```
+
+use binaryninjacore_sys::*;
+
+use crate::{
+    binaryview::{BinaryView, BinaryViewExt},
+    platform::Platform,
+    rc::*,
+    settings::Settings,
+    string::{BnStrCompatible, BnString},
+    types::{QualifiedName, Type},
+};
+
+use std::os::raw::{c_char, c_int};
+use std::ptr;
+
+pub use binaryninjacore_sys::BNTokenEscapingType as TokenEscapingType;
+
+pub struct CoreTypePrinter {
+    handle: *mut BNTypePrinter,
+}
+
+impl CoreTypePrinter {
+    pub(crate) fn from_raw(handle: *mut BNTypePrinter) -> Self {
+        debug_assert!(!handle.is_null());
+
+        Self { handle }
+    }
+
+    pub fn by_name<S: BnStrCompatible>(name: S) -> Option<Self> {
+        let name = name.into_bytes_with_nul();
+        let result = unsafe { BNGetTypePrinterByName(name.as_ref().as_ptr() as *const c_char) };
+        if result.is_null() {
+            return None;
+        }
+        Some(Self::from_raw(result))
+    }
+
+    pub fn list() -> Array<CoreTypePrinter> {
+        let mut count = 0;
+        let list = unsafe { BNGetTypePrinterList(&mut count) };
+        unsafe { Array::new(list, count, ()) }
+    }
+
+    /// The type printer selected by the `analysis.types.printerName` setting
+    pub fn try_default() -> Result<Self, ()> {
+        let settings = Settings::new("");
+        let name = settings.get_string("analysis.types.printerName", None, None);
+        Self::by_name(name).ok_or(())
+    }
+
+    pub fn name(&self) -> BnString {
+        unsafe { BnString::from_raw(BNGetTypePrinterName(self.handle)) }
+    }
+
+    /// Prints a single type as a declaration of `name`
+    pub fn type_string<S: BnStrCompatible>(
+        &self,
+        t: &Type,
+        platform: Option<&Platform>,
+        name: S,
+        escaping: TokenEscapingType,
+    ) -> Result<BnString, ()> {
+        let mut name = QualifiedName::from(name);
+        let mut result: *mut c_char = ptr::null_mut();
+        let success = unsafe {
+            BNGetTypePrinterTypeString(
+                self.handle,
+                t.handle,
+                platform.map_or(ptr::null_mut(), |p| p.handle),
+                &mut name.0,
+                escaping,
+                &mut result,
+            )
+        };
+
+        if !success || result.is_null() {
+            return Err(());
+        }
+        Ok(unsafe { BnString::from_raw(result) })
+    }
+
+    /// Prints all of `types` into one string, ordered so that every type is declared before it is
+    /// used and with forward declarations emitted for types that reference each other
+    pub fn print_all_types<'a, I, T>(
+        &self,
+        types: I,
+        view: &BinaryView,
+        line_width: usize,
+        escaping: TokenEscapingType,
+    ) -> Result<BnString, ()>
+    where
+        I: IntoIterator<Item = (&'a QualifiedName, T)>,
+        T: AsRef<Type>,
+    {
+        // Collected up front so the types stay alive while the core holds their raw handles
+        let types: Vec<(&QualifiedName, T)> = types.into_iter().collect();
+        let mut names: Vec<QualifiedName> = types.iter().map(|(name, _)| (*name).clone()).collect();
+        let mut raw_types: Vec<*mut BNType> =
+            types.iter().map(|(_, t)| t.as_ref().handle).collect();
+
+        let mut result: *mut c_char = ptr::null_mut();
+        let success = unsafe {
+            BNTypePrinterPrintAllTypes(
+                self.handle,
+                names.as_mut_ptr() as *mut BNQualifiedName,
+                raw_types.as_mut_ptr(),
+                raw_types.len(),
+                view.handle,
+                line_width as c_int,
+                escaping,
+                &mut result,
+            )
+        };
+
+        if !success || result.is_null() {
+            return Err(());
+        }
+        Ok(unsafe { BnString::from_raw(result) })
+    }
+}
+
+impl CoreArrayProvider for CoreTypePrinter {
+    type Raw = *mut BNTypePrinter;
+    type Context = ();
+}
+
+unsafe impl CoreOwnedArrayProvider for CoreTypePrinter {
+    unsafe fn free(raw: *mut Self::Raw, _count: usize, _context: &Self::Context) {
+        BNFreeTypePrinterList(raw);
+    }
+}
+
+unsafe impl<'a> CoreArrayWrapper<'a> for CoreTypePrinter {
+    type Wrapped = CoreTypePrinter;
+
+    unsafe fn wrap_raw(raw: &'a Self::Raw, _context: &'a Self::Context) -> Self::Wrapped {
+        CoreTypePrinter::from_raw(*raw)
+    }
+}
+
+/// Prints every type defined in `view` as compilable C declarations, using the default type printer
+///
+/// Identifiers are printed without Binary Ninja's backtick escaping, so the output can be consumed
+/// by compilers and other tools rather than only by Binary Ninja's own type parser.
+pub fn export_c_header(view: &BinaryView) -> Result<BnString, ()> {
+    let printer = CoreTypePrinter::try_default()?;
+    let types = view.types();
+
+    printer.print_all_types(
+        types.iter().map(|t| (t.name(), t.type_object())),
+        view,
+        80,
+        TokenEscapingType::NoTokenEscapingType,
+    )
+}
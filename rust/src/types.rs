@@ -325,6 +325,13 @@ impl TypeBuilder {
         self
     }
 
+    /// The pointed-to type for pointers, the element type for arrays, and the return type for functions
+    pub fn set_child_type<'a, T: Into<Conf<&'a Type>>>(&self, t: T) -> &Self {
+        let mut type_with_confidence = t.into().into();
+        unsafe { BNTypeBuilderSetChildType(self.handle, &mut type_with_confidence) };
+        self
+    }
+
     // Readable properties
 
     pub fn type_class(&self) -> TypeClass {